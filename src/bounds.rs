@@ -0,0 +1,238 @@
+
+use crate::{Math, Vector3};
+#[cfg(not(feature = "no_rays"))]
+use crate::Ray3;
+
+/// A struct that represents a 3D axis-aligned bounding box
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy)]
+pub struct Bounds {
+	/// The minimum corner of the box
+	min: Vector3,
+	/// The maximum corner of the box
+	max: Vector3,
+}
+
+/// Constructors
+impl Bounds {
+	/// Creates a new bounding box from two corners
+	/// - **min**: The minimum corner of the box
+	/// - **max**: The maximum corner of the box
+	/// 
+	/// **Returns**: Returns a new bounding box
+	/// #### Remarks
+	/// The corners are sorted per component, so swapping them will still create the same box
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3, Bounds};
+	/// let bounds = Bounds::from_min_max(Vector3::one(), Vector3::new(-1.0, 2.0, 0.0));
+	/// assert_eq!(Vector3::new(-1.0, 1.0, 0.0), bounds.min());
+	/// assert_eq!(Vector3::new(1.0, 2.0, 1.0), bounds.max());
+	/// ```
+	pub fn from_min_max(min: Vector3, max: Vector3) -> Self {
+		let (min_x, max_x) = Math::min_max(min.x(), max.x());
+		let (min_y, max_y) = Math::min_max(min.y(), max.y());
+		let (min_z, max_z) = Math::min_max(min.z(), max.z());
+		
+		Bounds {
+			min: Vector3::new(min_x, min_y, min_z),
+			max: Vector3::new(max_x, max_y, max_z),
+		}
+	}
+	
+	/// Creates a new bounding box from a center and a size
+	/// - **center**: The center of the box
+	/// - **size**: The full size of the box on each axis
+	/// 
+	/// **Returns**: Returns a new bounding box
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3, Bounds};
+	/// let bounds = Bounds::from_center_size(Vector3::zero(), Vector3::new(2.0, 4.0, 6.0));
+	/// assert_eq!(Vector3::new(-1.0, -2.0, -3.0), bounds.min());
+	/// assert_eq!(Vector3::new(1.0, 2.0, 3.0), bounds.max());
+	/// ```
+	pub fn from_center_size(center: Vector3, size: Vector3) -> Self {
+		let extents = 0.5 * size;
+		
+		Bounds::from_min_max(center - extents, center + extents)
+	}
+}
+
+/// Properties
+impl Bounds {
+	/// Gets the minimum corner of the box
+	/// 
+	/// **Returns**: Returns the minimum corner of the box
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3, Bounds};
+	/// let bounds = Bounds::from_min_max(Vector3::zero(), Vector3::one());
+	/// assert_eq!(Vector3::zero(), bounds.min());
+	/// ```
+	pub fn min(&self) -> Vector3 { self.min }
+	
+	/// Gets the maximum corner of the box
+	/// 
+	/// **Returns**: Returns the maximum corner of the box
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3, Bounds};
+	/// let bounds = Bounds::from_min_max(Vector3::zero(), Vector3::one());
+	/// assert_eq!(Vector3::one(), bounds.max());
+	/// ```
+	pub fn max(&self) -> Vector3 { self.max }
+	
+	/// Gets the center of the box
+	/// 
+	/// **Returns**: Returns the center of the box
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3, Bounds};
+	/// let bounds = Bounds::from_min_max(Vector3::zero(), Vector3::new(2.0, 4.0, 6.0));
+	/// assert_eq!(Vector3::new(1.0, 2.0, 3.0), bounds.center());
+	/// ```
+	pub fn center(&self) -> Vector3 { 0.5 * (self.min + self.max) }
+	
+	/// Gets the full size of the box on each axis
+	/// 
+	/// **Returns**: Returns the size of the box
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3, Bounds};
+	/// let bounds = Bounds::from_min_max(Vector3::one(), Vector3::new(2.0, 4.0, 6.0));
+	/// assert_eq!(Vector3::new(1.0, 3.0, 5.0), bounds.size());
+	/// ```
+	pub fn size(&self) -> Vector3 { self.max - self.min }
+	
+	/// Gets the extents of the box, which is half of the size
+	/// 
+	/// **Returns**: Returns the extents of the box
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3, Bounds};
+	/// let bounds = Bounds::from_min_max(Vector3::one(), Vector3::new(2.0, 4.0, 6.0));
+	/// assert_eq!(Vector3::new(0.5, 1.5, 2.5), bounds.extents());
+	/// ```
+	pub fn extents(&self) -> Vector3 { 0.5 * self.size() }
+}
+
+/// Public Methods
+impl Bounds {
+	/// Finds if the point is inside the box, including its surface
+	/// - **point**: The point to check with
+	/// 
+	/// **Returns**: Returns true if the point is inside the box
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3, Bounds};
+	/// let bounds = Bounds::from_min_max(Vector3::zero(), Vector3::one());
+	/// assert!(bounds.contains(Vector3::new(0.5, 0.2, 0.9)));
+	/// assert!(bounds.contains(Vector3::one()));
+	/// assert!(!bounds.contains(Vector3::new(0.5, 1.2, 0.9)));
+	/// ```
+	pub fn contains(&self, point: Vector3) -> bool {
+		point.x() >= self.min.x() && point.x() <= self.max.x()
+		&& point.y() >= self.min.y() && point.y() <= self.max.y()
+		&& point.z() >= self.min.z() && point.z() <= self.max.z()
+	}
+	
+	/// Grows the box so that it includes the given point
+	/// - **point**: The point to include into the box
+	/// 
+	/// **Returns**: Returns the grown box
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3, Bounds};
+	/// let bounds = Bounds::from_min_max(Vector3::zero(), Vector3::one());
+	/// let grown = bounds.expand_to_include(Vector3::new(2.0, -1.0, 0.5));
+	/// assert_eq!(Vector3::new(0.0, -1.0, 0.0), grown.min());
+	/// assert_eq!(Vector3::new(2.0, 1.0, 1.0), grown.max());
+	/// assert_eq!(grown, grown.expand_to_include(Vector3::new(0.5, 0.5, 0.5)));
+	/// ```
+	pub fn expand_to_include(self, point: Vector3) -> Self {
+		Bounds {
+			min: Vector3::new(
+				Math::min(self.min.x(), point.x()),
+				Math::min(self.min.y(), point.y()),
+				Math::min(self.min.z(), point.z())
+			),
+			max: Vector3::new(
+				Math::max(self.max.x(), point.x()),
+				Math::max(self.max.y(), point.y()),
+				Math::max(self.max.z(), point.z())
+			),
+		}
+	}
+	
+	/// Finds where the given ray intersects with the box
+	/// - **ray**: The ray to intersect the box with
+	/// 
+	/// **Returns**: Returns the distance along the ray where it enters the box, `0.0` if the ray starts inside the box,
+	/// or `None` if the ray misses or the box is behind the ray's origin
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3, Ray3, Bounds};
+	/// let bounds = Bounds::from_min_max(Vector3::zero(), Vector3::one());
+	/// let ray = Ray3::new(Vector3::new(0.5, 4.0, 0.5), Vector3::down());
+	/// assert_eq!(Some(3.0), bounds.intersect_ray(ray));
+	/// assert_eq!(None, bounds.intersect_ray(Ray3::new(Vector3::new(0.5, 4.0, 0.5), Vector3::up())));
+	/// ```
+	#[cfg(not(feature = "no_rays"))]
+	pub fn intersect_ray(self, ray: Ray3) -> Option<f32> { ray.intersect_aabb(self.min, self.max) }
+	
+	/// Finds if the two boxes overlap, including touching surfaces
+	/// - **other**: The other box to check with
+	/// 
+	/// **Returns**: Returns true if the two boxes overlap
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3, Bounds};
+	/// let a = Bounds::from_min_max(Vector3::zero(), Vector3::one());
+	/// let b = Bounds::from_min_max(Vector3::new(0.5, 0.5, 0.5), Vector3::new(2.0, 2.0, 2.0));
+	/// let c = Bounds::from_min_max(Vector3::new(1.5, 0.0, 0.0), Vector3::new(2.0, 1.0, 1.0));
+	/// assert!(a.intersects(b));
+	/// assert!(b.intersects(a));
+	/// assert!(!a.intersects(c));
+	/// ```
+	pub fn intersects(&self, other: Bounds) -> bool {
+		self.min.x() <= other.max.x() && self.max.x() >= other.min.x()
+		&& self.min.y() <= other.max.y() && self.max.y() >= other.min.y()
+		&& self.min.z() <= other.max.z() && self.max.z() >= other.min.z()
+	}
+	
+	/// Creates the smallest box that includes both boxes
+	/// - **other**: The other box to combine with
+	/// 
+	/// **Returns**: Returns the box that includes both boxes
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3, Bounds};
+	/// let a = Bounds::from_min_max(Vector3::zero(), Vector3::one());
+	/// let b = Bounds::from_min_max(Vector3::new(-2.0, 0.5, 0.5), Vector3::new(0.5, 0.5, 3.0));
+	/// let union = a.union(b);
+	/// assert_eq!(Vector3::new(-2.0, 0.0, 0.0), union.min());
+	/// assert_eq!(Vector3::new(1.0, 1.0, 3.0), union.max());
+	/// ```
+	pub fn union(self, other: Bounds) -> Self {
+		self.expand_to_include(other.min).expand_to_include(other.max)
+	}
+}
+
+unsafe impl Send for Bounds {}
+unsafe impl Sync for Bounds {}
+
+impl Eq for Bounds {}
+impl PartialEq for Bounds {
+	fn eq(&self, other: &Self) -> bool {
+		self.min == other.min
+		&& self.max == other.max
+	}
+}
+
+#[cfg(not(feature = "no_std"))]
+impl std::fmt::Display for Bounds {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.write_str(&format!("min: {}, max: {}", self.min, self.max))
+	}
+}
@@ -1,9 +1,12 @@
 
-use crate::Math;
+use crate::{Math, ColorParseError};
+use crate::{AddSubArithmetic, MulDivScalar, use_impl_ops, impl_add, impl_sub, impl_mul, impl_div};
 
 /// A structure for a color where each channel is a floating point value between 0.0 and 1.0
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 #[derive(Debug, Clone, Copy)]
+#[repr(C)]
 pub struct Color {
 	/// The red channel of the color
 	r: f32,
@@ -124,6 +127,8 @@ impl Color {
 	/// #### Remarks
 	/// If you are using `no_std` and are creating a color from a known name, this library specifically avoids trying to allocate memory
 	/// and as such the name must be all lowercases with no spaces or underscores whatsoever. So `olivedrab` is not the same as `Olive Drab` nor `olive_drab`.
+	/// 
+	/// To find out when the string is invalid instead of getting black, use [`Color::try_new_str`] or [`str::parse`].
 	/// #### Examples
 	/// ```
 	/// # use mathx::Color;
@@ -132,10 +137,96 @@ impl Color {
 	/// assert_eq!(expected, tomato);
 	/// ```
 	pub fn new_str(name_or_hex: &str) -> Self {
-		match from_known_name(name_or_hex) {
-			Option::Some(color) => color,
-			Option::None => Color::new(0.0, 0.0, 0.0),
+		match Color::try_new_str(name_or_hex) {
+			Result::Ok(color) => color,
+			Result::Err(_) => Color::new(0.0, 0.0, 0.0),
+		}
+	}
+	
+	/// Creates a new color using either a known name or a hex code, the same as [`Color::new_str`]
+	/// - **name_or_hex**: The known name or hex code for the color
+	/// 
+	/// **Returns**: Returns a new color using either a known name or hex code, or an error describing why the string is invalid
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Color, ColorParseError};
+	/// assert_eq!(Ok(Color::new_rgb(255, 99, 71)), Color::try_new_str("tomato"));
+	/// assert_eq!(Ok(Color::new_rgb(0x66, 0x66, 0x99)), Color::try_new_str("#669"));
+	/// assert_eq!(Err(ColorParseError::UnknownName), Color::try_new_str("bkack"));
+	/// assert_eq!(Err(ColorParseError::MalformedHex), Color::try_new_str("#GGGGGG"));
+	/// assert_eq!(Ok(Color::new_rgba(0x66, 0x66, 0x99, 0xDD)), Color::try_new_str("#669D"));
+	/// assert_eq!(Ok(Color::new_rgb(0x5A, 0x9C, 0xA4)), Color::try_new_str("#5A9CA4"));
+	/// assert_eq!(Ok(Color::new_rgba(0x5A, 0x9C, 0xA4, 0xDD)), Color::try_new_str("#5A9CA4DD"));
+	/// for invalid in ["#", "#1", "#12", "#12345", "#1234567", "#123456789", "#+12", "#12é"] {
+	/// 	assert_eq!(Err(ColorParseError::MalformedHex), Color::try_new_str(invalid));
+	/// }
+	/// ```
+	pub fn try_new_str(name_or_hex: &str) -> Result<Self, ColorParseError> {
+		if name_or_hex.starts_with("#") {
+			return from_hex(name_or_hex).ok_or(ColorParseError::MalformedHex);
 		}
+		
+		return from_known_name(name_or_hex).ok_or(ColorParseError::UnknownName);
+	}
+	
+	/// Creates a new color using hue, saturation, and value (HSV)
+	/// - **hue**: The hue of the color in degrees, wrapped between 0.0 and 360.0
+	/// - **saturation**: The saturation of the color, clamped between 0.0 and 1.0
+	/// - **value**: The value (brightness) of the color, clamped between 0.0 and 1.0
+	/// 
+	/// **Returns**: Returns a new opaque color using HSV
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// assert_eq!(Color::new_str("red"), Color::from_hsv(0.0, 1.0, 1.0));
+	/// assert_eq!(Color::new_str("red"), Color::from_hsv(360.0, 1.0, 1.0));
+	/// assert_eq!(Color::new_str("white"), Color::from_hsv(123.0, 0.0, 1.0));
+	/// assert_eq!(Color::new(0.2, 0.4, 0.6), Color::from_hsv(210.0, 0.6666667, 0.6));
+	/// ```
+	pub fn from_hsv(hue: f32, saturation: f32, value: f32) -> Self {
+		let saturation = Math::clamp(saturation, 0.0, 1.0);
+		let value = Math::clamp(value, 0.0, 1.0);
+		let chroma = value * saturation;
+		
+		return from_hue(hue, chroma, value - chroma);
+	}
+	
+	/// Creates a new color using hue, saturation, and lightness (HSL)
+	/// - **hue**: The hue of the color in degrees, wrapped between 0.0 and 360.0
+	/// - **saturation**: The saturation of the color, clamped between 0.0 and 1.0
+	/// - **lightness**: The lightness of the color, clamped between 0.0 and 1.0
+	/// 
+	/// **Returns**: Returns a new opaque color using HSL
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// assert_eq!(Color::new_str("red"), Color::from_hsl(0.0, 1.0, 0.5));
+	/// assert_eq!(Color::new(0.5, 0.5, 0.5), Color::from_hsl(123.0, 0.0, 0.5));
+	/// assert_eq!(Color::new(0.2, 0.4, 0.6), Color::from_hsl(210.0, 0.5, 0.4));
+	/// ```
+	pub fn from_hsl(hue: f32, saturation: f32, lightness: f32) -> Self {
+		let saturation = Math::clamp(saturation, 0.0, 1.0);
+		let lightness = Math::clamp(lightness, 0.0, 1.0);
+		let chroma = (1.0 - Math::abs(2.0 * lightness - 1.0)) * saturation;
+		
+		return from_hue(hue, chroma, lightness - 0.5 * chroma);
+	}
+	
+	/// Creates a new color from a packed integer in ARGB order (`0xAARRGGBB`)
+	/// - **packed**: The packed integer with the alpha channel in the highest byte and the blue channel in the lowest byte
+	/// 
+	/// **Returns**: Returns a new color from the packed integer
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// let color = Color::from_u32(0xFF8040C0);
+	/// assert_eq!(Color::new_rgba(0x80, 0x40, 0xC0, 0xFF), color);
+	/// assert_eq!(0xFF8040C0, color.to_u32());
+	/// ```
+	pub fn from_u32(packed: u32) -> Self {
+		let [a, r, g, b] = packed.to_be_bytes();
+		
+		return Color::new_rgba(r, g, b, a);
 	}
 }
 
@@ -318,8 +409,678 @@ impl Color {
 	pub fn set_alpha_as_byte(&mut self, value: u8) { self.a = value as f32 / 255.0 }
 }
 
+/// Creates an opaque black color, the same as using an unknown name with [`Color::new_str`]
+/// #### Examples
+/// ```
+/// # use mathx::Color;
+/// assert_eq!(Color::new(0.0, 0.0, 0.0), Color::default());
+/// assert_eq!(Color::new_str("not a color"), Color::default());
+/// ```
+impl Default for Color {
+	fn default() -> Self { Color::new(0.0, 0.0, 0.0) }
+}
+
+/// Public Methods
+impl Color {
+	/// Blends this color over the background color using straight (not premultiplied) alpha, also known as source-over
+	/// - **background**: The color to blend this color on top of
+	/// 
+	/// **Returns**: Returns the blended color with straight alpha
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// let white = Color::new_alpha(1.0, 1.0, 1.0, 0.5);
+	/// let black = Color::new(0.0, 0.0, 0.0);
+	/// assert_eq!(Color::new(0.5, 0.5, 0.5), white.blend_over(black));
+	/// let red = Color::new_alpha(1.0, 0.0, 0.0, 0.5);
+	/// let blue = Color::new_alpha(0.0, 0.0, 1.0, 0.5);
+	/// assert_eq!(Color::new_alpha(0.6666667, 0.0, 0.33333334, 0.75), red.blend_over(blue));
+	/// assert_eq!(black, Color::new_alpha(1.0, 1.0, 1.0, 0.0).blend_over(black));
+	/// assert_eq!(Color::new_str("blue"), Color::new_str("blue").blend_over(red));
+	/// ```
+	pub fn blend_over(self, background: Color) -> Self {
+		if self.a == 1.0 { return self; }
+		if self.a == 0.0 { return background; }
+		
+		let background_alpha = background.a * (1.0 - self.a);
+		let alpha = self.a + background_alpha;
+		
+		if alpha == 0.0 { return Color::new_alpha(0.0, 0.0, 0.0, 0.0); }
+		
+		return Color::new_alpha(
+			(self.r * self.a + background.r * background_alpha) / alpha,
+			(self.g * self.a + background.g * background_alpha) / alpha,
+			(self.b * self.a + background.b * background_alpha) / alpha,
+			alpha
+		);
+	}
+	
+	/// Gets the contrast ratio between the two colors, as defined by the WCAG accessibility guidelines
+	/// - **other**: The other color to compare against
+	/// 
+	/// **Returns**: Returns the contrast ratio between 1.0 (no contrast) and 21.0 (black and white)
+	/// #### Remarks
+	/// Both colors are expected to be in sRGB space and are converted to linear space to find their relative luminance.
+	/// The alpha channel is ignored, and the order of the colors does not matter
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Color,Math,assert_range};
+	/// let black = Color::new_str("black");
+	/// let white = Color::new_str("white");
+	/// assert_range!(21.0, black.contrast_ratio(white));
+	/// assert_range!(21.0, white.contrast_ratio(black));
+	/// assert_range!(1.0, Color::new_str("tomato").contrast_ratio(Color::new_str("tomato")));
+	/// assert_range!(4.5, Color::new_rgb(118, 118, 118).contrast_ratio(white), 0.1);
+	/// ```
+	pub fn contrast_ratio(self, other: Color) -> f32 {
+		let (darker, lighter) = Math::min_max(self.to_linear().luminance(), other.to_linear().luminance());
+		
+		return (lighter + 0.05) / (darker + 0.05);
+	}
+	
+	/// Darkens the color by adjusting its lightness in HSL, keeping the hue and alpha channel
+	/// - **amount**: The amount to subtract from the lightness, where the resulting lightness is clamped between 0.0 and 1.0
+	/// 
+	/// **Returns**: Returns the adjusted color
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// let white = Color::new_str("white");
+	/// assert_eq!(Color::new(0.5, 0.5, 0.5), white.darken(0.5));
+	/// assert_eq!(Color::new_str("black"), white.darken(2.0));
+	/// let red = Color::new_alpha(1.0, 0.0, 0.0, 0.5);
+	/// assert_eq!(Color::new_alpha(0.5, 0.0, 0.0, 0.5), red.darken(0.25));
+	/// ```
+	pub fn darken(self, amount: f32) -> Self { self.lighten(-amount) }
+	
+	/// Desaturates the color by adjusting its saturation in HSL, keeping the hue and alpha channel
+	/// - **amount**: The amount to subtract from the saturation, where the resulting saturation is clamped between 0.0 and 1.0
+	/// 
+	/// **Returns**: Returns the adjusted color
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// let red = Color::new_str("red");
+	/// assert_eq!(Color::new(0.5, 0.5, 0.5), red.desaturate(1.0));
+	/// assert_eq!(Color::new(0.75, 0.25, 0.25), red.desaturate(0.5));
+	/// ```
+	pub fn desaturate(self, amount: f32) -> Self { self.saturate(-amount) }
+	
+	/// Converts the color into a shade of gray using its luminance, keeping the alpha channel
+	/// 
+	/// **Returns**: Returns the grayscale color
+	/// #### Remarks
+	/// See [`Color::luminance`] for how the luminance is found
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// let color = Color::new_alpha(0.0, 1.0, 0.0, 0.5).grayscale();
+	/// assert_eq!(Color::new_alpha(0.7152, 0.7152, 0.7152, 0.5), color);
+	/// let white = Color::new_str("white");
+	/// assert_eq!(white, white.grayscale());
+	/// ```
+	pub fn grayscale(self) -> Self {
+		let luminance = self.luminance();
+		
+		return Color::new_alpha(luminance, luminance, luminance, self.a);
+	}
+	
+	/// Gets the exact bit patterns of each component, useful as a key for hashing and deduplicating
+	/// 
+	/// **Returns**: Returns the bit patterns of each component
+	/// #### Remarks
+	/// The key compares bitwise rather than approximately, so `-0.0` and `0.0` make different keys
+	/// and two colors that are approximately equal may still have different keys.
+	/// Color does not implement `Hash`, since its approximate equality would break the rule that equal values hash the same,
+	/// so use this key when hashing is needed
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// use std::collections::HashMap;
+	/// let mut names = HashMap::new();
+	/// names.insert(Color::new_str("tomato").hash_key(), "first");
+	/// assert_eq!(Some(&"first"), names.get(&Color::new_str("tomato").hash_key()));
+	/// assert_ne!(Color::new(0.0, 0.0, 0.0).hash_key(), Color::new(-0.0, 0.0, 0.0).hash_key());
+	/// ```
+	pub fn hash_key(&self) -> [u32; 4] { [self.r.to_bits(), self.g.to_bits(), self.b.to_bits(), self.a.to_bits()] }
+	
+	/// Linearly interpolates between this and the other color
+	/// - **rhs**: The other color to end from
+	/// - **t**: The ratio value to interpolate between both colors. Clamped between 0.0 and 1.0
+	/// 
+	/// **Returns**: Returns the interpolated color
+	/// #### Remarks
+	/// This interpolates the channels as they are stored, which are usually sRGB-encoded, so the result
+	/// may differ from interpolating the colors in linear space
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// let black = Color::new(0.0, 0.0, 0.0);
+	/// let white = Color::new(1.0, 1.0, 1.0);
+	/// assert_eq!(Color::new(0.5, 0.5, 0.5), black.lerp(white, 0.5));
+	/// assert_eq!(white, black.lerp(white, 2.0));
+	/// let clear = Color::new_alpha(1.0, 0.0, 0.0, 0.0);
+	/// assert_eq!(Color::new_alpha(1.0, 0.0, 0.0, 0.25), clear.lerp(Color::new_str("red"), 0.25));
+	/// ```
+	pub fn lerp(self, rhs: Color, t: f32) -> Self { self.lerp_unclamped(rhs, Math::clamp(t, 0.0, 1.0)) }
+	
+	/// Linearly interpolates between this and the other color (not clamped)
+	/// - **rhs**: The other color to end from
+	/// - **t**: The ratio value to interpolate between both colors
+	/// 
+	/// **Returns**: Returns the interpolated color, where each channel is still clamped between 0.0 and 1.0
+	/// #### Remarks
+	/// This interpolates the channels as they are stored, which are usually sRGB-encoded, so the result
+	/// may differ from interpolating the colors in linear space
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// let black = Color::new(0.0, 0.0, 0.0);
+	/// let gray = Color::new(0.5, 0.5, 0.5);
+	/// assert_eq!(Color::new(0.25, 0.25, 0.25), black.lerp_unclamped(gray, 0.5));
+	/// assert_eq!(Color::new(1.0, 1.0, 1.0), black.lerp_unclamped(gray, 3.0));
+	/// ```
+	pub fn lerp_unclamped(self, rhs: Color, t: f32) -> Self {
+		Color::new_alpha(
+			Math::lerp_unclamped(self.r, rhs.r, t),
+			Math::lerp_unclamped(self.g, rhs.g, t),
+			Math::lerp_unclamped(self.b, rhs.b, t),
+			Math::lerp_unclamped(self.a, rhs.a, t)
+		)
+	}
+	
+	/// Lightens the color by adjusting its lightness in HSL, keeping the hue and alpha channel
+	/// - **amount**: The amount to add to the lightness, where the resulting lightness is clamped between 0.0 and 1.0
+	/// 
+	/// **Returns**: Returns the adjusted color
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// let black = Color::new_str("black");
+	/// assert_eq!(Color::new(0.5, 0.5, 0.5), black.lighten(0.5));
+	/// let red = Color::new_alpha(1.0, 0.0, 0.0, 0.5);
+	/// assert_eq!(Color::new_alpha(1.0, 0.5, 0.5, 0.5), red.lighten(0.25));
+	/// ```
+	pub fn lighten(self, amount: f32) -> Self {
+		let (hue, saturation, lightness) = self.to_hsl();
+		
+		let color = Color::from_hsl(hue, saturation, lightness + amount);
+		
+		return Color::new_alpha(color.r, color.g, color.b, self.a);
+	}
+	
+	/// Gets the luminance of the color using the Rec. 709 weights (0.2126 red, 0.7152 green, 0.0722 blue)
+	/// 
+	/// **Returns**: Returns the luminance of the color between 0.0 and 1.0
+	/// #### Remarks
+	/// The channels are used as they are stored and are not converted from sRGB beforehand,
+	/// so the color should be in linear space to get the true relative luminance
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// let green = Color::new(0.0, 1.0, 0.0);
+	/// let blue = Color::new(0.0, 0.0, 1.0);
+	/// assert!(green.luminance() > blue.luminance());
+	/// assert_eq!(1.0, Color::new_str("white").luminance());
+	/// assert_eq!(0.0, Color::new_str("black").luminance());
+	/// ```
+	pub fn luminance(&self) -> f32 { 0.2126 * self.r + 0.7152 * self.g + 0.0722 * self.b }
+	
+	/// Multiplies the two colors together component-wise, which is commonly used to tint a color
+	/// - **rhs**: The other color to multiply with
+	/// 
+	/// **Returns**: Returns the multiplied color
+	/// #### Remarks
+	/// Colors can also be added and subtracted with each other, as well as multiplied and divided by a number.
+	/// All of these operations work on every channel (including alpha) and clamp each channel back between 0.0 and 1.0
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// let color = Color::new(1.0, 0.5, 0.2);
+	/// let tint = Color::new_alpha(0.5, 0.5, 1.0, 0.5);
+	/// assert_eq!(Color::new_alpha(0.5, 0.25, 0.2, 0.5), color.modulate(tint));
+	/// assert_eq!(Color::new_alpha(0.5, 0.25, 0.2, 0.5), color * tint);
+	/// let red = Color::new_str("red");
+	/// let green = Color::new_str("lime");
+	/// assert_eq!(Color::new_str("yellow"), red + green);
+	/// assert_eq!(Color::new_alpha(0.5, 0.0, 0.0, 0.5), red * 0.5);
+	/// assert_eq!(Color::new_alpha(0.5, 0.0, 0.0, 0.5), red / 2.0);
+	/// assert_eq!(Color::new_alpha(1.0, 0.0, 0.0, 0.0), (red + green) - green);
+	/// assert_eq!(Color::new_str("white"), red * 3.0 + Color::new(0.0, 1.0, 1.0));
+	/// ```
+	pub fn modulate(self, rhs: Color) -> Self {
+		Color::new_alpha(
+			self.r * rhs.r,
+			self.g * rhs.g,
+			self.b * rhs.b,
+			self.a * rhs.a
+		)
+	}
+	
+	/// Blends this color on top of the background color using the multiply blend mode, which darkens the background
+	/// - **background**: The color to blend this color on top of
+	/// 
+	/// **Returns**: Returns the blended color, keeping this color's alpha channel
+	/// #### Remarks
+	/// Only the red, green, and blue channels are blended, so use [`Color::blend_over`] afterwards to take transparency into account.
+	/// This differs from [`Color::modulate`], which also multiplies the alpha channels together
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// let gray = Color::new(0.5, 0.5, 0.5);
+	/// assert_eq!(Color::new(0.25, 0.25, 0.25), gray.multiply(gray));
+	/// let color = Color::new_alpha(1.0, 0.5, 0.2, 0.5);
+	/// assert_eq!(Color::new_alpha(0.5, 0.25, 0.1, 0.5), color.multiply(gray));
+	/// assert_eq!(color, Color::new_alpha(1.0, 1.0, 1.0, 0.5).multiply(color.with_alpha(1.0)));
+	/// ```
+	pub fn multiply(self, background: Color) -> Self {
+		Color::new_alpha(
+			self.r * background.r,
+			self.g * background.g,
+			self.b * background.b,
+			self.a
+		)
+	}
+	
+	/// Blends this color on top of the background color using the overlay blend mode, which increases the contrast of the background
+	/// - **background**: The color to blend this color on top of
+	/// 
+	/// **Returns**: Returns the blended color, keeping this color's alpha channel
+	/// #### Remarks
+	/// Each channel is multiplied where the background is darker than 0.5 and screened where it is lighter.
+	/// Only the red, green, and blue channels are blended, so use [`Color::blend_over`] afterwards to take transparency into account
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// let gray = Color::new(0.5, 0.5, 0.5);
+	/// let background = Color::new(0.25, 0.5, 0.75);
+	/// assert_eq!(background, gray.overlay(background));
+	/// let color = Color::new(0.8, 0.2, 0.5);
+	/// assert_eq!(Color::new(0.4, 0.2, 0.75), color.overlay(background));
+	/// assert_eq!(Color::new_str("black"), Color::new_str("white").overlay(Color::new_str("black")));
+	/// ```
+	pub fn overlay(self, background: Color) -> Self {
+		let blend = |top: f32, bottom: f32| if bottom < 0.5 {
+			2.0 * top * bottom
+		} else {
+			1.0 - 2.0 * (1.0 - top) * (1.0 - bottom)
+		};
+		
+		return Color::new_alpha(
+			blend(self.r, background.r),
+			blend(self.g, background.g),
+			blend(self.b, background.b),
+			self.a
+		);
+	}
+	
+	/// Premultiplies the color, multiplying the red, green, and blue channels by the alpha channel
+	/// 
+	/// **Returns**: Returns the premultiplied color, keeping the same alpha channel
+	/// #### Remarks
+	/// The premultiplied color is stored the same as any other color, but its channels no longer mean the same thing.
+	/// Each color channel ends up at most as large as the alpha channel, so methods that expect straight alpha (like
+	/// [`Color::blend_over`]) will not give the expected results. Use [`Color::unpremultiply`] to get back the straight color
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// let color = Color::new_alpha(1.0, 0.5, 0.2, 0.5);
+	/// assert_eq!(Color::new_alpha(0.5, 0.25, 0.1, 0.5), color.premultiply());
+	/// assert_eq!(Color::new_str("red"), Color::new_str("red").premultiply());
+	/// ```
+	pub fn premultiply(self) -> Self {
+		Color::new_alpha(
+			self.r * self.a,
+			self.g * self.a,
+			self.b * self.a,
+			self.a
+		)
+	}
+	
+	/// Saturates the color by adjusting its saturation in HSL, keeping the hue and alpha channel
+	/// - **amount**: The amount to add to the saturation, where the resulting saturation is clamped between 0.0 and 1.0
+	/// 
+	/// **Returns**: Returns the adjusted color
+	/// #### Remarks
+	/// Shades of gray don't have a hue, so saturating them will tint them towards red
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// let color = Color::new(0.75, 0.25, 0.25);
+	/// assert_eq!(Color::new_str("red"), color.saturate(0.5));
+	/// let color = Color::new_alpha(0.6, 0.4, 0.4, 0.5);
+	/// assert_eq!(Color::new_alpha(0.75, 0.25, 0.25, 0.5), color.saturate(0.3));
+	/// ```
+	pub fn saturate(self, amount: f32) -> Self {
+		let (hue, saturation, lightness) = self.to_hsl();
+		
+		let color = Color::from_hsl(hue, saturation + amount, lightness);
+		
+		return Color::new_alpha(color.r, color.g, color.b, self.a);
+	}
+	
+	/// Blends this color on top of the background color using the screen blend mode, which lightens the background
+	/// - **background**: The color to blend this color on top of
+	/// 
+	/// **Returns**: Returns the blended color, keeping this color's alpha channel
+	/// #### Remarks
+	/// Only the red, green, and blue channels are blended, so use [`Color::blend_over`] afterwards to take transparency into account
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// let color = Color::new(0.25, 0.5, 0.75);
+	/// assert_eq!(color, Color::new_str("black").screen(color));
+	/// assert_eq!(color, color.screen(Color::new_str("black")));
+	/// let gray = Color::new(0.5, 0.5, 0.5);
+	/// assert_eq!(Color::new(0.75, 0.75, 0.75), gray.screen(gray));
+	/// ```
+	pub fn screen(self, background: Color) -> Self {
+		Color::new_alpha(
+			1.0 - (1.0 - self.r) * (1.0 - background.r),
+			1.0 - (1.0 - self.g) * (1.0 - background.g),
+			1.0 - (1.0 - self.b) * (1.0 - background.b),
+			self.a
+		)
+	}
+	
+	/// Unpremultiplies the color, dividing the red, green, and blue channels by the alpha channel
+	/// 
+	/// **Returns**: Returns the straight alpha color, keeping the same alpha channel. Returns transparent black if the alpha channel is 0
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// let color = Color::new_alpha(0.5, 0.25, 0.1, 0.5);
+	/// assert_eq!(Color::new_alpha(1.0, 0.5, 0.2, 0.5), color.unpremultiply());
+	/// let color = Color::new_alpha(0.8, 0.4, 0.6, 0.5);
+	/// assert_eq!(color, color.premultiply().unpremultiply());
+	/// assert_eq!(Color::new_alpha(0.0, 0.0, 0.0, 0.0), Color::new_alpha(0.5, 0.5, 0.5, 0.0).unpremultiply());
+	/// ```
+	pub fn unpremultiply(self) -> Self {
+		if self.a == 0.0 { return Color::new_alpha(0.0, 0.0, 0.0, 0.0); }
+		
+		return Color::new_alpha(
+			self.r / self.a,
+			self.g / self.a,
+			self.b / self.a,
+			self.a
+		);
+	}
+	
+	/// Creates a copy of the color with the alpha channel replaced
+	/// - **value**: The value to set the alpha channel to, clamped between 0.0 and 1.0
+	/// 
+	/// **Returns**: Returns the color with the new alpha channel
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// let color = Color::new_str("red").with_alpha(0.5);
+	/// assert_eq!(Color::new_alpha(1.0, 0.0, 0.0, 0.5), color);
+	/// assert_eq!(0.0, color.with_alpha(-2.0).alpha());
+	/// ```
+	pub fn with_alpha(self, value: f32) -> Self {
+		let mut color = self;
+		
+		color.set_alpha(value);
+		return color;
+	}
+	
+	/// Creates a copy of the color with the blue channel replaced
+	/// - **value**: The value to set the blue channel to, clamped between 0.0 and 1.0
+	/// 
+	/// **Returns**: Returns the color with the new blue channel
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// let color = Color::new_str("red").with_blue(1.0);
+	/// assert_eq!(Color::new_str("magenta"), color);
+	/// ```
+	pub fn with_blue(self, value: f32) -> Self {
+		let mut color = self;
+		
+		color.set_blue(value);
+		return color;
+	}
+	
+	/// Creates a copy of the color with the green channel replaced
+	/// - **value**: The value to set the green channel to, clamped between 0.0 and 1.0
+	/// 
+	/// **Returns**: Returns the color with the new green channel
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// let color = Color::new_str("red").with_green(1.0);
+	/// assert_eq!(Color::new_str("yellow"), color);
+	/// ```
+	pub fn with_green(self, value: f32) -> Self {
+		let mut color = self;
+		
+		color.set_green(value);
+		return color;
+	}
+	
+	/// Creates a copy of the color with the red channel replaced
+	/// - **value**: The value to set the red channel to, clamped between 0.0 and 1.0
+	/// 
+	/// **Returns**: Returns the color with the new red channel
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// let color = Color::new_alpha(0.2, 0.4, 0.6, 0.8).with_red(0.9);
+	/// assert_eq!(Color::new_alpha(0.9, 0.4, 0.6, 0.8), color);
+	/// assert_eq!(1.0, color.with_red(3.0).red());
+	/// ```
+	pub fn with_red(self, value: f32) -> Self {
+		let mut color = self;
+		
+		color.set_red(value);
+		return color;
+	}
+}
+
+/// Conversions
+impl Color {
+	/// Converts the color into a hex code, ignoring the alpha channel
+	/// 
+	/// **Returns**: Returns the hex code of the color as `#RRGGBB`
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// let color = Color::new_str("#FF8000");
+	/// assert_eq!("#FF8000", color.to_hex());
+	/// assert_eq!(color, Color::new_str(&color.to_hex()));
+	/// let color = Color::new(0.5, 0.25, 1.0);
+	/// assert_eq!("#8040FF", color.to_hex());
+	/// ```
+	#[cfg(not(feature = "no_std"))]
+	pub fn to_hex(&self) -> String {
+		format!(
+			"#{:02X}{:02X}{:02X}",
+			round_to_byte(self.r),
+			round_to_byte(self.g),
+			round_to_byte(self.b)
+		)
+	}
+	
+	/// Converts the color into a hex code, including the alpha channel
+	/// 
+	/// **Returns**: Returns the hex code of the color as `#RRGGBBAA`
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// let color = Color::new_str("#FF8000");
+	/// assert_eq!("#FF8000FF", color.to_hex_alpha());
+	/// let color = Color::new_str("#5A9CA4DD");
+	/// assert_eq!("#5A9CA4DD", color.to_hex_alpha());
+	/// assert_eq!(color, Color::new_str(&color.to_hex_alpha()));
+	/// ```
+	#[cfg(not(feature = "no_std"))]
+	pub fn to_hex_alpha(&self) -> String {
+		format!(
+			"#{:02X}{:02X}{:02X}{:02X}",
+			round_to_byte(self.r),
+			round_to_byte(self.g),
+			round_to_byte(self.b),
+			round_to_byte(self.a)
+		)
+	}
+	
+	/// Converts the color into hue, saturation, and lightness (HSL), ignoring the alpha channel
+	/// 
+	/// **Returns**: Returns a tuple of the hue in degrees (between 0.0 and 360.0), the saturation, and the lightness respectively.
+	/// If the color is a shade of gray, then the hue and saturation are 0.0
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Color,Math,assert_range};
+	/// assert_eq!((0.0, 1.0, 0.5), Color::new_str("red").to_hsl());
+	/// assert_eq!((0.0, 0.0, 0.5), Color::new(0.5, 0.5, 0.5).to_hsl());
+	/// let (hue, saturation, lightness) = Color::new(0.2, 0.4, 0.6).to_hsl();
+	/// assert_range!(210.0, hue, 0.001);
+	/// assert_range!(0.5, saturation);
+	/// assert_range!(0.4, lightness);
+	/// assert_eq!(Color::new(0.2, 0.4, 0.6), Color::from_hsl(hue, saturation, lightness));
+	/// ```
+	pub fn to_hsl(&self) -> (f32, f32, f32) {
+		let max = Math::max(self.r, Math::max(self.g, self.b));
+		let min = Math::min(self.r, Math::min(self.g, self.b));
+		let chroma = max - min;
+		let lightness = 0.5 * (max + min);
+		let saturation = if chroma == 0.0 { 0.0 } else { chroma / (1.0 - Math::abs(2.0 * lightness - 1.0)) };
+		
+		return (get_hue(self, max, chroma), saturation, lightness);
+	}
+	
+	/// Converts the color into hue, saturation, and value (HSV), ignoring the alpha channel
+	/// 
+	/// **Returns**: Returns a tuple of the hue in degrees (between 0.0 and 360.0), the saturation, and the value respectively.
+	/// If the color is a shade of gray, then the hue is 0.0
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Color,Math,assert_range};
+	/// assert_eq!((0.0, 1.0, 1.0), Color::new_str("red").to_hsv());
+	/// assert_eq!((0.0, 0.0, 1.0), Color::new_str("white").to_hsv());
+	/// let (hue, saturation, value) = Color::new(0.2, 0.4, 0.6).to_hsv();
+	/// assert_range!(210.0, hue, 0.001);
+	/// assert_range!(0.6666667, saturation);
+	/// assert_range!(0.6, value);
+	/// assert_eq!(Color::new(0.2, 0.4, 0.6), Color::from_hsv(hue, saturation, value));
+	/// ```
+	pub fn to_hsv(&self) -> (f32, f32, f32) {
+		let max = Math::max(self.r, Math::max(self.g, self.b));
+		let min = Math::min(self.r, Math::min(self.g, self.b));
+		let chroma = max - min;
+		let saturation = if max == 0.0 { 0.0 } else { chroma / max };
+		
+		return (get_hue(self, max, chroma), saturation, max);
+	}
+	
+	/// Converts the color from sRGB space into linear space using the sRGB transfer function, keeping the alpha channel
+	/// 
+	/// **Returns**: Returns the color in linear space
+	/// #### Remarks
+	/// Lighting and blending are more accurate in linear space, use [`Color::to_srgb`] to convert back for display
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Color,Math,assert_range};
+	/// let color = Color::new_alpha(0.5, 0.0, 1.0, 0.5).to_linear();
+	/// assert_range!(0.21404114, color.red(), 0.005);
+	/// assert_eq!(0.0, color.green());
+	/// assert_range!(1.0, color.blue(), 0.005);
+	/// assert_eq!(0.5, color.alpha());
+	/// ```
+	pub fn to_linear(&self) -> Color {
+		Color::new_alpha(
+			srgb_to_linear(self.r),
+			srgb_to_linear(self.g),
+			srgb_to_linear(self.b),
+			self.a
+		)
+	}
+	
+	/// Converts the color from linear space into sRGB space using the sRGB transfer function, keeping the alpha channel
+	/// 
+	/// **Returns**: Returns the color in sRGB space
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Color,Math,assert_range};
+	/// let color = Color::new_alpha(0.21404114, 0.0, 1.0, 0.5).to_srgb();
+	/// assert_range!(0.5, color.red(), 0.005);
+	/// assert_eq!(0.0, color.green());
+	/// assert_range!(1.0, color.blue(), 0.005);
+	/// assert_eq!(0.5, color.alpha());
+	/// let color = Color::new(0.2, 0.4, 0.6);
+	/// let actual = color.to_linear().to_srgb();
+	/// assert_range!(color.red(), actual.red(), 0.005);
+	/// assert_range!(color.green(), actual.green(), 0.005);
+	/// assert_range!(color.blue(), actual.blue(), 0.005);
+	/// ```
+	pub fn to_srgb(&self) -> Color {
+		Color::new_alpha(
+			linear_to_srgb(self.r),
+			linear_to_srgb(self.g),
+			linear_to_srgb(self.b),
+			self.a
+		)
+	}
+	
+	/// Converts the color into a packed integer in ARGB order (`0xAARRGGBB`), rounding each channel to the nearest byte
+	/// 
+	/// **Returns**: Returns the packed integer with the alpha channel in the highest byte and the blue channel in the lowest byte
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// let color = Color::new_str("#8040C0");
+	/// assert_eq!(0xFF8040C0, color.to_u32());
+	/// assert_eq!(color, Color::from_u32(color.to_u32()));
+	/// assert_eq!(0x80FF0000, Color::new_alpha(1.0, 0.0, 0.0, 0.5).to_u32());
+	/// ```
+	pub fn to_u32(&self) -> u32 {
+		u32::from_be_bytes([
+			round_to_byte(self.a),
+			round_to_byte(self.r),
+			round_to_byte(self.g),
+			round_to_byte(self.b)
+		])
+	}
+	
+	/// Writes the color as a hex code, including the alpha channel, without allocating any memory
+	/// - **f**: The formatter to write the hex code into
+	/// 
+	/// **Returns**: Returns the result of writing into the formatter
+	/// #### Remarks
+	/// This is what the color's `Display` uses, so formatting a color with `{}` writes the same `#RRGGBBAA` hex code
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// struct Hex(Color);
+	/// impl core::fmt::Display for Hex {
+	/// 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result { self.0.write_hex(f) }
+	/// }
+	/// assert_eq!("#5A9CA4DD", format!("{}", Hex(Color::new_str("#5A9CA4DD"))));
+	/// ```
+	pub fn write_hex(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(
+			f,
+			"#{:02X}{:02X}{:02X}{:02X}",
+			round_to_byte(self.r),
+			round_to_byte(self.g),
+			round_to_byte(self.b),
+			round_to_byte(self.a)
+		)
+	}
+}
+
+const _: () = assert!(core::mem::size_of::<Color>() == 16 && core::mem::align_of::<Color>() == 4);
+
 // Equates
 impl Eq for Color {}
+/// Compares each channel of the colors approximately, using [`Math::approx`]
+/// #### Examples
+/// ```
+/// # use mathx::Color;
+/// assert!(Color::new_str("red") == Color::new_rgb(255, 0, 0));
+/// assert!(Color::new(0.5, 0.25, 1.0) == Color::new(0.5000001, 0.25, 1.0));
+/// assert!(Color::new_str("red") != Color::new_rgba(255, 0, 0, 254));
+/// ```
 impl PartialEq for Color {
 	fn eq(&self, other: &Self) -> bool {
 		Math::approx(self.r, other.r)
@@ -329,16 +1090,259 @@ impl PartialEq for Color {
 	}
 }
 
+/// Compares each component of the colors within an absolute epsilon, using the `approx` crate
+/// #### Examples
+/// ```
+/// # #[cfg(feature = "approx")] {
+/// # use mathx::Color;
+/// # use approx::{assert_abs_diff_eq, assert_abs_diff_ne};
+/// assert_abs_diff_eq!(Color::new(0.5, 0.25, 1.0), Color::new(0.5000001, 0.25, 1.0));
+/// assert_abs_diff_ne!(Color::new(0.5, 0.25, 1.0), Color::new(0.6, 0.25, 1.0));
+/// assert_abs_diff_eq!(Color::new(0.5, 0.25, 1.0), Color::new(0.6, 0.25, 1.0), epsilon = 0.2);
+/// # }
+/// ```
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Color {
+	type Epsilon = f32;
+	
+	fn default_epsilon() -> Self::Epsilon { f32::default_epsilon() }
+	
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		f32::abs_diff_eq(&self.r, &other.r, epsilon)
+		&& f32::abs_diff_eq(&self.g, &other.g, epsilon)
+		&& f32::abs_diff_eq(&self.b, &other.b, epsilon)
+		&& f32::abs_diff_eq(&self.a, &other.a, epsilon)
+	}
+}
+
+/// Compares each component of the colors with a relative tolerance, using the `approx` crate
+/// #### Examples
+/// ```
+/// # #[cfg(feature = "approx")] {
+/// # use mathx::Color;
+/// # use approx::{assert_relative_eq, assert_relative_ne};
+/// assert_relative_eq!(Color::new(0.5, 0.25, 1.0), Color::new(0.5000001, 0.25, 1.0));
+/// assert_relative_ne!(Color::new(0.5, 0.25, 1.0), Color::new(0.6, 0.25, 1.0));
+/// assert_relative_eq!(Color::new(0.5, 0.25, 1.0), Color::new(0.6, 0.25, 1.0), max_relative = 0.2);
+/// # }
+/// ```
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Color {
+	fn default_max_relative() -> Self::Epsilon { f32::default_max_relative() }
+	
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		f32::relative_eq(&self.r, &other.r, epsilon, max_relative)
+		&& f32::relative_eq(&self.g, &other.g, epsilon, max_relative)
+		&& f32::relative_eq(&self.b, &other.b, epsilon, max_relative)
+		&& f32::relative_eq(&self.a, &other.a, epsilon, max_relative)
+	}
+}
+
 // Display
-#[cfg(not(feature = "no_std"))]
-impl std::fmt::Display for Color {
-	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		f.write_str(&format!("({}, {}, {}, {})", self.r, self.g, self.b, self.a))
+/// Formats the color as a `#RRGGBBAA` hex code using [`Color::write_hex`], which does not need `std`
+/// #### Examples
+/// ```
+/// # use mathx::Color;
+/// use core::fmt::Write;
+/// 
+/// struct Buffer { bytes: [u8; 9], length: usize }
+/// impl Write for Buffer {
+/// 	fn write_str(&mut self, value: &str) -> core::fmt::Result {
+/// 		let end = self.length + value.len();
+/// 		if end > self.bytes.len() { return Err(core::fmt::Error); }
+/// 		self.bytes[self.length..end].copy_from_slice(value.as_bytes());
+/// 		self.length = end;
+/// 		Ok(())
+/// 	}
+/// }
+/// 
+/// let mut buffer = Buffer { bytes: [0; 9], length: 0 };
+/// write!(buffer, "{}", Color::new_str("red")).unwrap();
+/// assert_eq!(b"#FF0000FF", &buffer.bytes);
+/// ```
+impl core::fmt::Display for Color {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result { self.write_hex(f) }
+}
+
+// Parsing
+/// Parses a color from either a known name or a hex code, the same as [`Color::try_new_str`]
+/// #### Examples
+/// ```
+/// # use mathx::{Color, ColorParseError};
+/// assert_eq!(Ok(Color::new_rgb(255, 99, 71)), "tomato".parse::<Color>());
+/// assert_eq!(Ok(Color::new_rgba(0x5A, 0x9C, 0xA4, 0xDD)), "#5A9CA4DD".parse::<Color>());
+/// assert_eq!(Err(ColorParseError::UnknownName), "not a color".parse::<Color>());
+/// ```
+impl core::str::FromStr for Color {
+	type Err = ColorParseError;
+	
+	fn from_str(name_or_hex: &str) -> Result<Self, Self::Err> { Color::try_new_str(name_or_hex) }
+}
+
+// Arithmetic
+impl AddSubArithmetic<Color> for Color {
+	type Output = Color;
+	fn add_other(self, rhs: Color) -> Self::Output {
+		Color::new_alpha(
+			self.r + rhs.r,
+			self.g + rhs.g,
+			self.b + rhs.b,
+			self.a + rhs.a
+		)
+	}
+	fn add_assign_other(&mut self, rhs: Color) {
+		*self = self.add_other(rhs);
+	}
+	fn subtract_other(self, rhs: Color) -> Self::Output {
+		Color::new_alpha(
+			self.r - rhs.r,
+			self.g - rhs.g,
+			self.b - rhs.b,
+			self.a - rhs.a
+		)
+	}
+	fn subtract_assign_other(&mut self, rhs: Color) {
+		*self = self.subtract_other(rhs);
+	}
+}
+
+impl MulDivScalar for Color {
+	type Output = Color;
+	fn multiply_scalar(self, rhs: f32) -> Self::Output {
+		Color::new_alpha(
+			rhs * self.r,
+			rhs * self.g,
+			rhs * self.b,
+			rhs * self.a
+		)
+	}
+	fn multiply_assign_scalar(&mut self, rhs: f32) {
+		*self = self.multiply_scalar(rhs);
+	}
+	fn divide_scalar(self, rhs: f32) -> Self::Output {
+		if rhs == 0.0 { return Color::new_alpha(0.0, 0.0, 0.0, 0.0); }
+		Color::new_alpha(
+			self.r / rhs,
+			self.g / rhs,
+			self.b / rhs,
+			self.a / rhs
+		)
+	}
+	fn divide_assign_scalar(&mut self, rhs: f32) {
+		*self = self.divide_scalar(rhs);
+	}
+	fn reciprocal_scalar(self, rhs: f32) -> Self::Output {
+		Color::new_alpha(
+			if self.r != 0.0 { rhs / self.r } else { 0.0 },
+			if self.g != 0.0 { rhs / self.g } else { 0.0 },
+			if self.b != 0.0 { rhs / self.b } else { 0.0 },
+			if self.a != 0.0 { rhs / self.a } else { 0.0 }
+		)
+	}
+}
+
+use_impl_ops!();
+impl_add!(Color);
+impl_sub!(Color);
+impl_mul!(Color);
+impl_mul!(Color, Color => Color: modulate);
+impl_div!(Color);
+
+// Serde
+/// Deserializes the color from either its channels or from a known name or hex code (the same as [`Color::new_str`]).
+/// Human-readable formats accept both, while other formats only accept the channels
+/// #### Examples
+/// ```
+/// # #[cfg(feature = "serde")] {
+/// # use mathx::Color;
+/// let color = Color::new_alpha(1.0, 0.5, 0.25, 0.75);
+/// let json = serde_json::to_string(&color).unwrap();
+/// assert_eq!(color, serde_json::from_str::<Color>(&json).unwrap());
+/// let color: Color = serde_json::from_str("\"#FF0000\"").unwrap();
+/// assert_eq!(Color::new(1.0, 0.0, 0.0), color);
+/// let color: Color = serde_json::from_str("\"tomato\"").unwrap();
+/// assert_eq!(Color::new_rgb(255, 99, 71), color);
+/// assert!(serde_json::from_str::<Color>("\"not a color\"").is_err());
+/// # }
+/// ```
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Color {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+		if deserializer.is_human_readable() {
+			deserializer.deserialize_any(ColorVisitor)
+		}
+		else {
+			deserializer.deserialize_struct("Color", &["r", "g", "b", "a"], ColorVisitor)
+		}
+	}
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum ColorField { R, G, B, A }
+
+#[cfg(feature = "serde")]
+struct ColorVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> serde::de::Visitor<'de> for ColorVisitor {
+	type Value = Color;
+	
+	fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+		formatter.write_str("a color's channels, known name, or hex code")
+	}
+	
+	fn visit_str<E>(self, value: &str) -> Result<Self::Value, E> where E: serde::de::Error {
+		match from_known_name(value) {
+			Option::Some(color) => Result::Ok(color),
+			Option::None => Result::Err(E::invalid_value(serde::de::Unexpected::Str(value), &self)),
+		}
+	}
+	
+	fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: serde::de::SeqAccess<'de> {
+		let mut channels = [0.0f32; 4];
+		
+		for (i, channel) in channels.iter_mut().enumerate() {
+			*channel = match seq.next_element()? {
+				Option::Some(value) => value,
+				Option::None => return Result::Err(serde::de::Error::invalid_length(i, &self)),
+			};
+		}
+		
+		return Result::Ok(Color::new_alpha(channels[0], channels[1], channels[2], channels[3]));
+	}
+	
+	fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: serde::de::MapAccess<'de> {
+		let mut channels = [Option::None; 4];
+		
+		while let Option::Some(field) = map.next_key()? {
+			let (index, name) = match field {
+				ColorField::R => (0, "r"),
+				ColorField::G => (1, "g"),
+				ColorField::B => (2, "b"),
+				ColorField::A => (3, "a"),
+			};
+			
+			if channels[index].is_some() { return Result::Err(serde::de::Error::duplicate_field(name)); }
+			channels[index] = Option::Some(map.next_value::<f32>()?);
+		}
+		
+		let [r, g, b, a] = channels;
+		
+		return Result::Ok(Color::new_alpha(
+			r.ok_or_else(|| serde::de::Error::missing_field("r"))?,
+			g.ok_or_else(|| serde::de::Error::missing_field("g"))?,
+			b.ok_or_else(|| serde::de::Error::missing_field("b"))?,
+			a.ok_or_else(|| serde::de::Error::missing_field("a"))?
+		));
 	}
 }
 
 fn from_hex(hex: &str) -> Option<Color> {
 	if !hex.starts_with("#") { return Option::None; }
+	if !matches!(hex.len(), 4 | 5 | 7 | 9) { return Option::None; }
+	if !hex[1..].bytes().all(|byte| byte.is_ascii_hexdigit()) { return Option::None; }
 	
 	let mut red = 0u8;
 	let mut green = 0u8;
@@ -390,6 +1394,43 @@ fn from_hex(hex: &str) -> Option<Color> {
 	Option::Some(Color::new_rgba(red, green, blue, alpha))
 }
 
+fn round_to_byte(value: f32) -> u8 { Math::round(value * 255.0) as u8 }
+
+fn from_hue(hue: f32, chroma: f32, offset: f32) -> Color {
+	let sector = 6.0 * Math::fract(hue / 360.0);
+	let x = chroma * (1.0 - Math::abs(sector - 2.0 * Math::floor(0.5 * sector) - 1.0));
+	let (r, g, b) = match sector as u8 {
+		0 => (chroma, x, 0.0),
+		1 => (x, chroma, 0.0),
+		2 => (0.0, chroma, x),
+		3 => (0.0, x, chroma),
+		4 => (x, 0.0, chroma),
+		_ => (chroma, 0.0, x),
+	};
+	
+	return Color::new(r + offset, g + offset, b + offset);
+}
+
+fn get_hue(color: &Color, max: f32, chroma: f32) -> f32 {
+	if chroma == 0.0 { return 0.0; }
+	
+	let hue = if max == color.r { (color.g - color.b) / chroma }
+		else if max == color.g { (color.b - color.r) / chroma + 2.0 }
+		else { (color.r - color.g) / chroma + 4.0 };
+	
+	return if hue < 0.0 { 60.0 * hue + 360.0 } else { 60.0 * hue };
+}
+
+fn srgb_to_linear(value: f32) -> f32 {
+	if value <= 0.04045 { value / 12.92 }
+	else { Math::pow((value + 0.055) / 1.055, 2.4) }
+}
+
+fn linear_to_srgb(value: f32) -> f32 {
+	if value <= 0.0031308 { value * 12.92 }
+	else { 1.055 * Math::pow(value, 1.0 / 2.4) - 0.055 }
+}
+
 fn get_byte_from_doubled_hex(hex: &str) -> Result<u8, ()> {
 	let num = match u8::from_str_radix(hex, 16) {
 		Result::Err(_) => return Result::Err(()),
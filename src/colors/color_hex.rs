@@ -0,0 +1,38 @@
+
+use crate::Color;
+
+/// Serializes the color as a `#RRGGBBAA` hex code
+/// - **color**: The color to serialize
+/// - **serializer**: The serializer to write the hex code with
+/// 
+/// **Returns**: Returns the result of the serializer
+pub fn serialize<S>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+	serializer.collect_str(color)
+}
+
+/// Deserializes the color from a 3, 4, 6, or 8 digit hex code that starts with `#`
+/// - **deserializer**: The deserializer to read the hex code from
+/// 
+/// **Returns**: Returns the deserialized color, or an error if the string is not a valid hex code
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Color, D::Error> where D: serde::Deserializer<'de> {
+	deserializer.deserialize_str(HexVisitor)
+}
+
+struct HexVisitor;
+
+impl<'de> serde::de::Visitor<'de> for HexVisitor {
+	type Value = Color;
+	
+	fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+		formatter.write_str("a color's hex code")
+	}
+	
+	fn visit_str<E>(self, value: &str) -> Result<Self::Value, E> where E: serde::de::Error {
+		if !value.starts_with("#") { return Result::Err(E::invalid_value(serde::de::Unexpected::Str(value), &self)); }
+		
+		match Color::try_new_str(value) {
+			Result::Ok(color) => Result::Ok(color),
+			Result::Err(_) => Result::Err(E::invalid_value(serde::de::Unexpected::Str(value), &self)),
+		}
+	}
+}
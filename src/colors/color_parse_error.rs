@@ -0,0 +1,22 @@
+
+/// An error returned when a string could not be parsed into a color
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorParseError {
+	/// The string is not a hex code and does not match any known color name
+	UnknownName,
+	/// The string starts with `#` but is not a valid 3, 4, 6, or 8 digit hex code
+	MalformedHex,
+}
+
+#[cfg(not(feature = "no_std"))]
+impl std::fmt::Display for ColorParseError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			ColorParseError::UnknownName => f.write_str("the string does not match any known color name"),
+			ColorParseError::MalformedHex => f.write_str("the string is not a valid hex code"),
+		}
+	}
+}
+
+#[cfg(not(feature = "no_std"))]
+impl std::error::Error for ColorParseError {}
@@ -1,3 +1,39 @@
 
 mod color;
 pub use color::Color;
+
+mod color_parse_error;
+pub use color_parse_error::ColorParseError;
+
+/// Serializes and deserializes a [`Color`] as a `#RRGGBBAA` hex code, for use with `#[serde(with = "mathx::color_hex")]`.
+/// Deserializing accepts any 3, 4, 6, or 8 digit hex code, the same as [`Color::new_str`]
+/// #### Remarks
+/// Colors still serialize as their channels by default, so this only changes the fields that opt into it
+/// #### Examples
+/// ```
+/// # #[cfg(feature = "serde")] {
+/// # use mathx::Color;
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Theme {
+/// 	#[serde(with = "mathx::color_hex")]
+/// 	background: Color,
+/// 	foreground: Color,
+/// }
+/// let theme = Theme {
+/// 	background: Color::new_rgba(255, 128, 0, 255),
+/// 	foreground: Color::new(1.0, 1.0, 1.0),
+/// };
+/// let json = serde_json::to_value(&theme).unwrap();
+/// assert_eq!("#FF8000FF", json["background"]);
+/// assert_eq!(1.0, json["foreground"]["r"]);
+/// let theme: Theme = serde_json::from_value(json).unwrap();
+/// assert_eq!(Color::new_rgba(255, 128, 0, 255), theme.background);
+/// let json = r##"{ "background": "#0F0", "foreground": { "r": 0.0, "g": 0.0, "b": 0.0, "a": 1.0 } }"##;
+/// let theme: Theme = serde_json::from_str(json).unwrap();
+/// assert_eq!(Color::new(0.0, 1.0, 0.0), theme.background);
+/// let json = r##"{ "background": "lime", "foreground": { "r": 0.0, "g": 0.0, "b": 0.0, "a": 1.0 } }"##;
+/// assert!(serde_json::from_str::<Theme>(json).is_err());
+/// # }
+/// ```
+#[cfg(feature = "serde")]
+pub mod color_hex;
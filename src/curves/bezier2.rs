@@ -0,0 +1,246 @@
+use crate::Math;
+use crate::Vector2;
+
+/// A cubic Bézier curve in 2D space that holds a start point, two control points, and an end point
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy)]
+pub struct Bezier2 {
+	/// The point the curve starts at
+	start: Vector2,
+	/// The control point that pulls the curve away from the start point
+	first_control: Vector2,
+	/// The control point that pulls the curve in towards the end point
+	second_control: Vector2,
+	/// The point the curve ends at
+	end: Vector2,
+}
+
+/// Constructors
+impl Bezier2 {
+	/// Creates a new cubic Bézier curve in 2D space
+	/// - **start**: The point the curve starts at
+	/// - **first_control**: The control point that pulls the curve away from the start point
+	/// - **second_control**: The control point that pulls the curve in towards the end point
+	/// - **end**: The point the curve ends at
+	/// 
+	/// **Returns**: Returns a new cubic Bézier curve
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// # use mathx::curves::Bezier2;
+	/// let curve = Bezier2::new(Vector2::zero(), Vector2::up(), Vector2::one(), Vector2::right());
+	/// assert_eq!(Vector2::zero(), curve.start());
+	/// assert_eq!(Vector2::up(), curve.first_control());
+	/// assert_eq!(Vector2::one(), curve.second_control());
+	/// assert_eq!(Vector2::right(), curve.end());
+	/// ```
+	pub fn new(start: Vector2, first_control: Vector2, second_control: Vector2, end: Vector2) -> Self {
+		Bezier2 { start, first_control, second_control, end }
+	}
+}
+
+/// Properties
+impl Bezier2 {
+	/// Gets the start point of the curve
+	/// 
+	/// **Returns**: Returns the start point of the curve
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// # use mathx::curves::Bezier2;
+	/// let curve = Bezier2::new(Vector2::zero(), Vector2::up(), Vector2::one(), Vector2::right());
+	/// assert_eq!(Vector2::zero(), curve.start());
+	/// ```
+	pub fn start(&self) -> Vector2 { self.start }
+	
+	/// Sets the start point of the curve
+	/// - **value**: The value to set the start point to
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// # use mathx::curves::Bezier2;
+	/// let mut curve = Bezier2::new(Vector2::zero(), Vector2::up(), Vector2::one(), Vector2::right());
+	/// curve.set_start(Vector2::down());
+	/// assert_eq!(Vector2::down(), curve.start());
+	/// ```
+	pub fn set_start(&mut self, value: Vector2) { self.start = value; }
+	
+	/// Gets the first control point of the curve
+	/// 
+	/// **Returns**: Returns the first control point of the curve
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// # use mathx::curves::Bezier2;
+	/// let curve = Bezier2::new(Vector2::zero(), Vector2::up(), Vector2::one(), Vector2::right());
+	/// assert_eq!(Vector2::up(), curve.first_control());
+	/// ```
+	pub fn first_control(&self) -> Vector2 { self.first_control }
+	
+	/// Sets the first control point of the curve
+	/// - **value**: The value to set the first control point to
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// # use mathx::curves::Bezier2;
+	/// let mut curve = Bezier2::new(Vector2::zero(), Vector2::up(), Vector2::one(), Vector2::right());
+	/// curve.set_first_control(Vector2::down());
+	/// assert_eq!(Vector2::down(), curve.first_control());
+	/// ```
+	pub fn set_first_control(&mut self, value: Vector2) { self.first_control = value; }
+	
+	/// Gets the second control point of the curve
+	/// 
+	/// **Returns**: Returns the second control point of the curve
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// # use mathx::curves::Bezier2;
+	/// let curve = Bezier2::new(Vector2::zero(), Vector2::up(), Vector2::one(), Vector2::right());
+	/// assert_eq!(Vector2::one(), curve.second_control());
+	/// ```
+	pub fn second_control(&self) -> Vector2 { self.second_control }
+	
+	/// Sets the second control point of the curve
+	/// - **value**: The value to set the second control point to
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// # use mathx::curves::Bezier2;
+	/// let mut curve = Bezier2::new(Vector2::zero(), Vector2::up(), Vector2::one(), Vector2::right());
+	/// curve.set_second_control(Vector2::down());
+	/// assert_eq!(Vector2::down(), curve.second_control());
+	/// ```
+	pub fn set_second_control(&mut self, value: Vector2) { self.second_control = value; }
+	
+	/// Gets the end point of the curve
+	/// 
+	/// **Returns**: Returns the end point of the curve
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// # use mathx::curves::Bezier2;
+	/// let curve = Bezier2::new(Vector2::zero(), Vector2::up(), Vector2::one(), Vector2::right());
+	/// assert_eq!(Vector2::right(), curve.end());
+	/// ```
+	pub fn end(&self) -> Vector2 { self.end }
+	
+	/// Sets the end point of the curve
+	/// - **value**: The value to set the end point to
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// # use mathx::curves::Bezier2;
+	/// let mut curve = Bezier2::new(Vector2::zero(), Vector2::up(), Vector2::one(), Vector2::right());
+	/// curve.set_end(Vector2::down());
+	/// assert_eq!(Vector2::down(), curve.end());
+	/// ```
+	pub fn set_end(&mut self, value: Vector2) { self.end = value; }
+}
+
+/// Public Methods
+impl Bezier2 {
+	/// Gets the approximate length of the curve by adding up the lengths of straight segments along the curve
+	/// - **segments**: The amount of straight segments to split the curve into, more segments are more accurate
+	/// 
+	/// **Returns**: Returns the approximate length of the curve
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// # use mathx::curves::Bezier2;
+	/// # use mathx::{Math,assert_range};
+	/// let curve = Bezier2::new(Vector2::zero(), Vector2::right(), Vector2::new(2.0, 0.0), Vector2::new(3.0, 0.0));
+	/// assert_range!(3.0, curve.length(1));
+	/// assert_range!(3.0, curve.length(10));
+	/// let curve = Bezier2::new(Vector2::zero(), Vector2::up(), Vector2::one(), Vector2::right());
+	/// assert_range!(2.0, curve.length(10), 0.01);
+	/// ```
+	pub fn length(&self, segments: u32) -> f32 {
+		let segments = if segments == 0 { 1 } else { segments };
+		let mut length = 0.0;
+		let mut previous = self.start;
+		
+		for i in 1..=segments {
+			let point = self.point_at(i as f32 / segments as f32);
+			
+			length += previous.distance(point);
+			previous = point;
+		}
+		
+		return length;
+	}
+	
+	/// Gets the point along the curve using De Casteljau's algorithm
+	/// - **t**: The ratio along the curve to get the point from. Clamped between 0.0 and 1.0
+	/// 
+	/// **Returns**: Returns the point along the curve
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// # use mathx::curves::Bezier2;
+	/// let curve = Bezier2::new(Vector2::zero(), Vector2::up(), Vector2::one(), Vector2::right());
+	/// assert_eq!(Vector2::zero(), curve.point_at(0.0));
+	/// assert_eq!(Vector2::right(), curve.point_at(1.0));
+	/// assert_eq!(Vector2::new(0.5, 0.75), curve.point_at(0.5));
+	/// ```
+	pub fn point_at(&self, t: f32) -> Vector2 {
+		let t = Math::clamp(t, 0.0, 1.0);
+		let a = self.start.lerp_unclamped(self.first_control, t);
+		let b = self.first_control.lerp_unclamped(self.second_control, t);
+		let c = self.second_control.lerp_unclamped(self.end, t);
+		let d = a.lerp_unclamped(b, t);
+		let e = b.lerp_unclamped(c, t);
+		
+		return d.lerp_unclamped(e, t);
+	}
+	
+	/// Gets the tangent (the derivative) of the curve at the given point along the curve
+	/// - **t**: The ratio along the curve to get the tangent from. Clamped between 0.0 and 1.0
+	/// 
+	/// **Returns**: Returns the tangent of the curve, which is not normalized
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// # use mathx::curves::Bezier2;
+	/// let curve = Bezier2::new(Vector2::zero(), Vector2::up(), Vector2::one(), Vector2::right());
+	/// assert_eq!(Vector2::new(0.0, 3.0), curve.tangent_at(0.0));
+	/// assert_eq!(Vector2::new(0.0, -3.0), curve.tangent_at(1.0));
+	/// assert_eq!(Vector2::new(1.5, 0.0), curve.tangent_at(0.5));
+	/// ```
+	pub fn tangent_at(&self, t: f32) -> Vector2 {
+		let t = Math::clamp(t, 0.0, 1.0);
+		let inverse = 1.0 - t;
+		
+		return (3.0 * inverse * inverse) * (self.first_control - self.start)
+			+ (6.0 * inverse * t) * (self.second_control - self.first_control)
+			+ (3.0 * t * t) * (self.end - self.second_control);
+	}
+}
+
+unsafe impl Send for Bezier2 {}
+unsafe impl Sync for Bezier2 {}
+
+// Equates
+impl Eq for Bezier2 {}
+impl PartialEq for Bezier2 {
+	fn eq(&self, other: &Self) -> bool {
+		self.start == other.start
+		&& self.first_control == other.first_control
+		&& self.second_control == other.second_control
+		&& self.end == other.end
+	}
+}
+
+// Display
+#[cfg(not(feature = "no_std"))]
+impl std::fmt::Display for Bezier2 {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.write_str(&format!(
+			"{{ start: {}, first_control: {}, second_control: {}, end: {} }}",
+			self.start,
+			self.first_control,
+			self.second_control,
+			self.end
+		))
+	}
+}
@@ -0,0 +1,111 @@
+use crate::Math;
+use crate::Vector3;
+
+/// A Catmull-Rom spline in 3D space that smoothly passes through each of its points
+#[derive(Debug, Clone, Copy)]
+pub struct CatmullRom<'a> {
+	/// The points that the spline passes through
+	points: &'a [Vector3],
+}
+
+/// Constructors
+impl<'a> CatmullRom<'a> {
+	/// Creates a new Catmull-Rom spline that passes through the given points
+	/// - **points**: The points that the spline passes through, in order
+	/// 
+	/// **Returns**: Returns a new Catmull-Rom spline
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// # use mathx::curves::CatmullRom;
+	/// let points = [Vector3::zero(), Vector3::up(), Vector3::one()];
+	/// let spline = CatmullRom::new(&points);
+	/// assert_eq!(3, spline.points().len());
+	/// ```
+	pub fn new(points: &'a [Vector3]) -> Self { CatmullRom { points } }
+}
+
+/// Properties
+impl<'a> CatmullRom<'a> {
+	/// Gets the points that the spline passes through
+	/// 
+	/// **Returns**: Returns the points that the spline passes through
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// # use mathx::curves::CatmullRom;
+	/// let points = [Vector3::zero(), Vector3::up(), Vector3::one()];
+	/// let spline = CatmullRom::new(&points);
+	/// assert_eq!(Vector3::up(), spline.points()[1]);
+	/// ```
+	pub fn points(&self) -> &'a [Vector3] { self.points }
+	
+	/// Sets the points that the spline passes through
+	/// - **value**: The points to set the spline to pass through
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// # use mathx::curves::CatmullRom;
+	/// let points = [Vector3::zero(), Vector3::up(), Vector3::one()];
+	/// let other_points = [Vector3::forward(), Vector3::back()];
+	/// let mut spline = CatmullRom::new(&points);
+	/// spline.set_points(&other_points);
+	/// assert_eq!(2, spline.points().len());
+	/// ```
+	pub fn set_points(&mut self, value: &'a [Vector3]) { self.points = value; }
+}
+
+/// Public Methods
+impl<'a> CatmullRom<'a> {
+	/// Gets the point along the whole spline, where the first and last points are repeated so the spline reaches them
+	/// - **t**: The ratio along the whole spline to get the point from. Clamped between 0.0 and 1.0
+	/// 
+	/// **Returns**: Returns the point along the spline. If there are no points, this returns a zero vector
+	/// #### Remarks
+	/// Each point is evenly spaced along `t`, so the spline passes through point `i` of `n` points at `t = i / (n - 1)`
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// # use mathx::curves::CatmullRom;
+	/// let points = [
+	/// 	Vector3::zero(),
+	/// 	Vector3::new(1.0, 2.0, 0.0),
+	/// 	Vector3::new(3.0, 2.0, 1.0),
+	/// 	Vector3::new(4.0, 0.0, 1.0),
+	/// ];
+	/// let spline = CatmullRom::new(&points);
+	/// assert_eq!(points[0], spline.point_at(0.0));
+	/// assert_eq!(points[1], spline.point_at(1.0 / 3.0));
+	/// assert_eq!(points[2], spline.point_at(2.0 / 3.0));
+	/// assert_eq!(points[3], spline.point_at(1.0));
+	/// assert_eq!(Vector3::new(2.0, 2.25, 0.5), spline.point_at(0.5));
+	/// let points = [Vector3::one()];
+	/// assert_eq!(Vector3::one(), CatmullRom::new(&points).point_at(0.5));
+	/// ```
+	pub fn point_at(&self, t: f32) -> Vector3 {
+		let count = self.points.len();
+		
+		if count == 0 { return Vector3::zero(); }
+		if count == 1 { return self.points[0]; }
+		
+		let scaled = Math::clamp(t, 0.0, 1.0) * (count - 1) as f32;
+		let index = if scaled as usize >= count - 1 { count - 2 } else { scaled as usize };
+		let t = scaled - index as f32;
+		let t2 = t * t;
+		let t3 = t2 * t;
+		let p0 = self.points[if index == 0 { 0 } else { index - 1 }];
+		let p1 = self.points[index];
+		let p2 = self.points[index + 1];
+		let p3 = self.points[if index + 2 >= count { count - 1 } else { index + 2 }];
+		
+		return 0.5 * (
+			2.0 * p1
+			+ t * (p2 - p0)
+			+ t2 * (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3)
+			+ t3 * (3.0 * p1 - p0 - 3.0 * p2 + p3)
+		);
+	}
+}
+
+unsafe impl<'a> Send for CatmullRom<'a> {}
+unsafe impl<'a> Sync for CatmullRom<'a> {}
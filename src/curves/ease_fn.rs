@@ -0,0 +1,108 @@
+use crate::Math;
+
+/// An easing function that can be selected by name, useful for picking easings from data such as config files
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EaseFn {
+	/// Moves at a constant speed
+	Linear,
+	/// Starts slow and speeds up, using a quadratic curve
+	InQuad,
+	/// Starts fast and slows down, using a quadratic curve
+	OutQuad,
+	/// Starts and ends slow, using a quadratic curve
+	InOutQuad,
+	/// Starts slow and speeds up, using a cubic curve
+	InCubic,
+	/// Starts fast and slows down, using a cubic curve
+	OutCubic,
+	/// Starts and ends slow, using a cubic curve
+	InOutCubic,
+	/// Starts slow and speeds up, using a sine curve
+	InSine,
+	/// Starts fast and slows down, using a sine curve
+	OutSine,
+	/// Starts and ends slow, using a sine curve
+	InOutSine,
+	/// Starts very slow and speeds up sharply, using an exponential curve
+	InExpo,
+	/// Starts very fast and slows down sharply, using an exponential curve
+	OutExpo,
+	/// Starts and ends very slow, using an exponential curve
+	InOutExpo,
+}
+
+/// Public Methods
+impl EaseFn {
+	/// Interpolates between the start and end values using the easing function
+	/// - **start**: The value to start from
+	/// - **end**: The value to end at
+	/// - **t**: The ratio value to interpolate between both values, clamped between 0.0 and 1.0
+	/// 
+	/// **Returns**: Returns the eased value between the start and end values
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Math,assert_range};
+	/// # use mathx::curves::EaseFn;
+	/// assert_range!(5.0, EaseFn::Linear.apply(0.0, 10.0, 0.5));
+	/// assert_range!(2.5, EaseFn::InQuad.apply(0.0, 10.0, 0.5));
+	/// assert_range!(7.5, EaseFn::OutQuad.apply(0.0, 10.0, 0.5));
+	/// assert_range!(5.0, EaseFn::InOutQuad.apply(0.0, 10.0, 0.5));
+	/// assert_range!(10.0, EaseFn::InQuad.apply(0.0, 10.0, 2.0));
+	/// let all = [
+	/// 	EaseFn::Linear, EaseFn::InQuad, EaseFn::OutQuad, EaseFn::InOutQuad,
+	/// 	EaseFn::InCubic, EaseFn::OutCubic, EaseFn::InOutCubic,
+	/// 	EaseFn::InSine, EaseFn::OutSine, EaseFn::InOutSine,
+	/// 	EaseFn::InExpo, EaseFn::OutExpo, EaseFn::InOutExpo,
+	/// ];
+	/// for ease in all {
+	/// 	assert_range!(-2.0, ease.apply(-2.0, 6.0, 0.0), 0.001);
+	/// 	assert_range!(6.0, ease.apply(-2.0, 6.0, 1.0), 0.001);
+	/// }
+	/// ```
+	pub fn apply(self, start: f32, end: f32, t: f32) -> f32 {
+		Math::lerp_unclamped(start, end, self.ease(Math::clamp(t, 0.0, 1.0)))
+	}
+	
+	/// Eases the ratio value using the easing function
+	/// - **t**: The ratio value to ease, expected to be between 0.0 and 1.0
+	/// 
+	/// **Returns**: Returns the eased ratio, where 0.0 and 1.0 stay as they are
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Math,assert_range};
+	/// # use mathx::curves::EaseFn;
+	/// assert_range!(0.125, EaseFn::InCubic.ease(0.5));
+	/// assert_range!(0.875, EaseFn::OutCubic.ease(0.5));
+	/// assert_range!(0.0, EaseFn::InExpo.ease(0.0));
+	/// assert_range!(0.03125, EaseFn::InExpo.ease(0.5));
+	/// assert_range!(0.5, EaseFn::InOutSine.ease(0.5));
+	/// ```
+	pub fn ease(self, t: f32) -> f32 {
+		match self {
+			EaseFn::Linear => t,
+			EaseFn::InQuad => t * t,
+			EaseFn::OutQuad => 1.0 - (1.0 - t) * (1.0 - t),
+			EaseFn::InOutQuad => if t < 0.5 {
+				2.0 * t * t
+			} else { 1.0 - 2.0 * (1.0 - t) * (1.0 - t) },
+			EaseFn::InCubic => t * t * t,
+			EaseFn::OutCubic => 1.0 - (1.0 - t) * (1.0 - t) * (1.0 - t),
+			EaseFn::InOutCubic => if t < 0.5 {
+				4.0 * t * t * t
+			} else { 1.0 - 4.0 * (1.0 - t) * (1.0 - t) * (1.0 - t) },
+			EaseFn::InSine => 1.0 - Math::cos(Math::PI_OVER_2 * t),
+			EaseFn::OutSine => Math::sin(Math::PI_OVER_2 * t),
+			EaseFn::InOutSine => 0.5 * (1.0 - Math::cos(Math::PI * t)),
+			EaseFn::InExpo => if t <= 0.0 { 0.0 } else { Math::exp2(10.0 * t - 10.0) },
+			EaseFn::OutExpo => if t >= 1.0 { 1.0 } else { 1.0 - Math::exp2(-10.0 * t) },
+			EaseFn::InOutExpo => if t <= 0.0 {
+				0.0
+			} else if t >= 1.0 {
+				1.0
+			} else if t < 0.5 {
+				0.5 * Math::exp2(20.0 * t - 10.0)
+			} else { 1.0 - 0.5 * Math::exp2(-20.0 * t + 10.0) },
+		}
+	}
+}
@@ -0,0 +1,12 @@
+
+mod bezier2;
+pub use bezier2::Bezier2;
+
+mod bezier3;
+pub use bezier3::Bezier3;
+
+mod catmull_rom;
+pub use catmull_rom::CatmullRom;
+
+mod ease_fn;
+pub use ease_fn::EaseFn;
@@ -5,9 +5,9 @@ mod math;
 pub use math::Math;
 pub mod interfaces;
 
-#[cfg(not(all(feature = "no_vectors", feature = "no_quaternions")))]
+#[cfg(not(all(feature = "no_vectors", feature = "no_quaternions", feature = "no_colors")))]
 mod arithmetic;
-#[cfg(not(all(feature = "no_vectors", feature = "no_quaternions")))]
+#[cfg(not(all(feature = "no_vectors", feature = "no_quaternions", feature = "no_colors")))]
 pub(crate) use arithmetic::*;
 
 #[cfg(not(feature = "no_quaternions"))]
@@ -23,17 +23,32 @@ pub use vectors::{Vector3, Vector2};
 #[cfg(not(all(feature = "no_rays", feature = "no_vectors")))]
 mod rays;
 #[cfg(not(all(feature = "no_rays", feature = "no_vectors")))]
-pub use rays::{Ray2, Ray3};
+pub use rays::{Ray2, Ray3, LineSegment3};
 
 #[cfg(not(all(feature = "no_planes", feature = "no_vectors")))]
 mod plane;
 #[cfg(not(all(feature = "no_planes", feature = "no_vectors")))]
 pub use plane::Plane;
 
+#[cfg(not(any(feature = "no_bounds", feature = "no_vectors")))]
+mod bounds;
+#[cfg(not(any(feature = "no_bounds", feature = "no_vectors")))]
+pub use bounds::Bounds;
+
+#[cfg(not(any(feature = "no_matrices", feature = "no_vectors")))]
+mod matrices;
+#[cfg(not(any(feature = "no_matrices", feature = "no_vectors")))]
+pub use matrices::{Matrix3, Matrix4};
+
+#[cfg(not(any(feature = "no_curves", feature = "no_vectors")))]
+pub mod curves;
+
 #[cfg(not(feature = "no_collision"))]
 pub mod collision;
 
 #[cfg(not(feature = "no_colors"))]
 mod colors;
 #[cfg(not(feature = "no_colors"))]
-pub use colors::Color;
+pub use colors::{Color, ColorParseError};
+#[cfg(all(feature = "serde", not(feature = "no_colors")))]
+pub use colors::color_hex;
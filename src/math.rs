@@ -92,6 +92,34 @@ impl Math {
 		Math::abs(a - b) < epsilon
 	}
 	
+	/// Finds if the floating point number is approximately zero. Checks with epsilon = 0.000001, the same as [`Math::approx`]
+	/// - **value**: The number to check with
+	/// 
+	/// **Returns**: Returns true if the value is approximately zero
+	/// #### Examples
+	/// ```
+	/// # use mathx::Math;
+	/// assert!(Math::approx_zero(0.0000001));
+	/// assert!(Math::approx_zero(0.0));
+	/// assert!(Math::approx_zero(-0.0000001));
+	/// assert!(!Math::approx_zero(0.1));
+	/// ```
+	pub fn approx_zero(value: f32) -> bool { Math::approx(value, 0.0) }
+	
+	/// Finds if the floating point number is approximately zero, provided the epsilon
+	/// - **value**: The number to check with
+	/// - **epsilon**: The epsilon (smallest possible difference from zero) to check with
+	/// 
+	/// **Returns**: Returns true if the value is approximately zero
+	/// #### Examples
+	/// ```
+	/// # use mathx::Math;
+	/// assert!(Math::approx_zero_epsilon(0.0001, 0.001));
+	/// assert!(Math::approx_zero_epsilon(0.0, 0.001));
+	/// assert!(!Math::approx_zero_epsilon(0.1, 0.001));
+	/// ```
+	pub fn approx_zero_epsilon(value: f32, epsilon: f32) -> bool { Math::approx_epsilon(value, 0.0, epsilon) }
+	
 	/// Computes the arc cosine (a.k.a. inverse cosine) with the provided value
 	/// - **value**: The value to compute the arc cosine with, must be within -1 and 1
 	/// 
@@ -384,32 +412,22 @@ impl Math {
 	/// assert_range!(-1.3734008, value);
 	/// let value = Math::atan2(-1.0, 5.0);
 	/// assert_range!(-0.19739556, value);
+	/// let value = Math::atan2(1.0, -5.0);
+	/// assert_range!(2.9441972, value);
+	/// let value = Math::atan2(-5.0, -1.0);
+	/// assert_range!(-1.7681919, value);
+	/// let value = Math::atan2(0.0, -1.0);
+	/// assert_range!(Math::PI, value);
+	/// let value = Math::atan2(-1.0, 0.0);
+	/// assert_range!(-Math::PI_OVER_2, value);
+	/// let value = Math::atan2(0.0, 0.0);
+	/// assert_range!(0.0, value);
+	/// let value = Math::atan2(-3e38, -3e38);
+	/// assert_range!(-3.0 * Math::PI_OVER_4, value);
 	/// ```
 	pub fn atan2(y: f32, x: f32) -> f32 {
 		#[cfg(not(feature = "no_std"))] { y.atan2(x) }
-		#[cfg(feature = "no_std")] {
-			let mut a = Math::abs(x);
-			let mut b = Math::abs(y);
-			let mut c = Math::max(a, b);
-			b = Math::min(a, b);
-			a = c.recip();
-			a = b * a;
-		  
-			let d = a * a;
-			c = -0.013480470;
-			c = c * d + 0.057477314;
-			c = c * d - 0.121239071;
-			c = c * d + 0.195635925;
-			c = c * d - 0.332994597;
-			c = c * d + 0.999995630;
-			a *= c;
-			
-			if Math::abs(y) > Math::abs(x) { a = Math::PI_OVER_2 - a; }
-			if x < 0.0 { a = Math::PI - a; }
-			if y < 0.0 { a *= -1.0; }
-			
-			return a;
-		}
+		#[cfg(feature = "no_std")] { Math::cordic_vectoring(x, y).0 }
 	}
 	
 	/// Computes the arc tangent (a.k.a. inverse tangent) with the provided x and y values
@@ -486,6 +504,35 @@ impl Math {
 	/// ```
 	pub fn clamp(value: f32, min: f32, max: f32) -> f32 { value.clamp(min, max) }
 	
+	/// Wraps the value around the range instead of clamping it, for cyclic values like hues or headings
+	/// - **value**: The value to wrap
+	/// - **range**: The range to wrap around, where the start is included and the end is not
+	/// 
+	/// **Returns**: Returns the wrapped value, or the start of the range if the range is empty
+	/// #### Remarks
+	/// Unlike [`Math::repeat`], the end of the range wraps back around to the start, so values always land in `[start, end)`.
+	/// Values below the range wrap around from the end of the range
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Math,assert_range};
+	/// assert_range!(10.0, Math::clamp_wrapped(370.0, 0.0..360.0), 0.001);
+	/// assert_range!(330.0, Math::clamp_wrapped(-30.0, 0.0..360.0), 0.001);
+	/// assert_range!(0.0, Math::clamp_wrapped(360.0, 0.0..360.0));
+	/// assert_range!(0.75, Math::clamp_wrapped(0.25, 0.5..1.0));
+	/// assert_range!(0.5, Math::clamp_wrapped(2.5, -1.0..1.0));
+	/// assert_eq!(2.0, Math::clamp_wrapped(5.0, 2.0..2.0));
+	/// assert_eq!(0.0, Math::clamp_wrapped(-1e-6, 0.0..360.0));
+	/// ```
+	pub fn clamp_wrapped(value: f32, range: Range<f32>) -> f32 {
+		let distance = range.end - range.start;
+		
+		if distance == 0.0 { return range.start; }
+		
+		let wrapped = range.start + distance * Math::fract((value - range.start) / distance);
+		
+		return if wrapped >= range.end { range.start } else { wrapped };
+	}
+	
 	/// Computes the cosine of the given angle in radians
 	/// - **angle**: The angle to compute cosine with in radians
 	/// 
@@ -781,6 +828,64 @@ impl Math {
 	/// ```
 	pub fn fract(value: f32) -> f32 { value - Math::floor(value) }
 	
+	/// Computes the gamma function, which extends the factorial to real numbers so that Γ(n) = (n - 1)!
+	/// - **value**: The value to compute the gamma function of
+	/// 
+	/// **Returns**: Returns the gamma function of the value. Returns infinity when the value is 0 and
+	/// `NaN` when the value is a negative integer, since those are the poles of the function
+	/// #### Remarks
+	/// This uses the Lanczos approximation, and the reflection formula for values less than 0.5
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Math,assert_range};
+	/// assert_range!(24.0, Math::gamma(5.0), 0.001);
+	/// assert_range!(1.0, Math::gamma(1.0), 0.0001);
+	/// assert_range!(Math::sqrt(Math::PI), Math::gamma(0.5), 0.0001);
+	/// assert_range!(-3.5449077, Math::gamma(-0.5), 0.001);
+	/// assert!(Math::gamma(0.0).is_infinite());
+	/// assert!(Math::gamma(-2.0).is_nan());
+	/// assert_range!(8.841762e30, Math::gamma(30.0), 1e28);
+	/// assert!(Math::gamma(100.0).is_infinite());
+	/// ```
+	pub fn gamma(value: f32) -> f32 {
+		if value == 0.0 { return f32::INFINITY; }
+		if value < 0.0 && Math::fract(value) == 0.0 { return f32::NAN; }
+		if value < 0.5 {
+			return Math::PI / (Math::sin(Math::PI * value) * Math::gamma(1.0 - value));
+		}
+		
+		let x = value - 1.0;
+		let t = x + 7.5;
+		
+		return Math::sqrt(Math::TWO_PI) * Math::exp((x + 0.5) * Math::ln(t) - t) * Math::lanczos_sum(x);
+	}
+	
+	/// Computes the length of the hypotenuse of a right triangle with the given sides
+	/// - **x**: The length of the first side
+	/// - **y**: The length of the second side
+	/// 
+	/// **Returns**: Returns the length of the hypotenuse, `sqrt(x² + y²)`
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Math,assert_range};
+	/// let value = Math::hypot(3.0, 4.0);
+	/// assert_range!(5.0, value);
+	/// let value = Math::hypot(-1.0, 1.0);
+	/// assert_range!(1.4142135, value);
+	/// let value = Math::hypot(0.0, -2.5);
+	/// assert_range!(2.5, value);
+	/// let value = Math::hypot(0.0, 0.0);
+	/// assert_eq!(0.0, value);
+	/// let value = Math::hypot(3e20, 4e20);
+	/// assert_range!(5e20, value, 1e15);
+	/// let value = Math::hypot(3e38, 3e38);
+	/// assert!(value.is_infinite());
+	/// ```
+	pub fn hypot(x: f32, y: f32) -> f32 {
+		#[cfg(not(feature = "no_std"))] { x.hypot(y) }
+		#[cfg(feature = "no_std")] { Math::cordic_vectoring(x, y).1 }
+	}
+	
 	/// Linearly interpolates between the first and second values
 	/// - **a**: The first value to start from
 	/// - **b**: The second value to end from
@@ -801,6 +906,29 @@ impl Math {
 	/// ```
 	pub fn lerp(a: f32, b: f32, t: f32) -> f32 { Math::lerp_unclamped(a, b, Math::clamp(t, 0.0, 1.0)) }
 	
+	/// Linearly interpolates between the first and second values (not clamped), landing exactly on both ends
+	/// - **a**: The first value to start from
+	/// - **b**: The second value to end from
+	/// - **t**: The ratio value to interpolate between both values
+	/// 
+	/// **Returns**: Returns the interpolated value
+	/// #### Remarks
+	/// This uses `(1 - t) * a + t * b` instead of the `a + t * (b - a)` used by [`Math::lerp`] and [`Math::lerp_unclamped`].
+	/// It always returns exactly `a` when `t` is 0.0 and exactly `b` when `t` is 1.0, even when the two values are far apart in
+	/// magnitude, at the cost of an extra multiplication
+	/// #### Examples
+	/// ```
+	/// # use mathx::Math;
+	/// let value = Math::lerp_precise(0.0, 1.0, 0.5);
+	/// assert_eq!(0.5, value);
+	/// let value = Math::lerp_precise(-10.0, 10.0, 0.75);
+	/// assert_eq!(5.0, value);
+	/// assert_ne!(1.0, Math::lerp_unclamped(100000000.0, 1.0, 1.0));
+	/// assert_eq!(1.0, Math::lerp_precise(100000000.0, 1.0, 1.0));
+	/// assert_eq!(100000000.0, Math::lerp_precise(100000000.0, 1.0, 0.0));
+	/// ```
+	pub fn lerp_precise(a: f32, b: f32, t: f32) -> f32 { (1.0 - t) * a + t * b }
+	
 	/// Linearly interpolates between the first and second values (not clamped)
 	/// - **a**: The first value to start from
 	/// - **b**: The second value to end from
@@ -821,6 +949,36 @@ impl Math {
 	/// ```
 	pub fn lerp_unclamped(a: f32, b: f32, t: f32) -> f32 { a + t * (b - a) }
 	
+	/// Computes the natural log of the absolute value of the gamma function
+	/// - **value**: The value to compute the log-gamma function of
+	/// 
+	/// **Returns**: Returns the natural log of the absolute value of the gamma function. Returns infinity when
+	/// the value is 0 or a negative integer, since those are the poles of the gamma function
+	/// #### Remarks
+	/// This is useful for large values, where the gamma function itself would overflow
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Math,assert_range};
+	/// assert_range!(Math::ln(24.0), Math::lgamma(5.0), 0.001);
+	/// assert_range!(0.0, Math::lgamma(1.0), 0.0001);
+	/// assert_range!(0.5723649, Math::lgamma(0.5), 0.001);
+	/// assert_range!(857.9337, Math::lgamma(200.0), 0.1);
+	/// assert_range!(1.2655121, Math::lgamma(-0.5), 0.001);
+	/// assert!(Math::lgamma(0.0).is_infinite());
+	/// assert!(Math::lgamma(-3.0).is_infinite());
+	/// ```
+	pub fn lgamma(value: f32) -> f32 {
+		if value <= 0.0 && Math::fract(value) == 0.0 { return f32::INFINITY; }
+		if value < 0.5 {
+			return Math::ln(Math::PI / Math::abs(Math::sin(Math::PI * value))) - Math::lgamma(1.0 - value);
+		}
+		
+		let x = value - 1.0;
+		let t = x + 7.5;
+		
+		return 0.5 * Math::ln(Math::TWO_PI) + (x + 0.5) * Math::ln(t) - t + Math::ln(Math::lanczos_sum(x));
+	}
+	
 	/// Computes the natural log of the given number
 	/// - **value**: The value to compute the natural log of
 	/// 
@@ -841,6 +999,12 @@ impl Math {
 	/// assert_range!(0.69314718056, value);
 	/// let value = Math::ln(10.0);
 	/// assert_range!(2.30258509299, value);
+	/// let value = Math::ln(1.45);
+	/// assert_range!(0.37156356, value, 0.00001);
+	/// let value = Math::ln(1.5);
+	/// assert_range!(0.4054651, value, 0.00001);
+	/// let value = Math::ln(1.95);
+	/// assert_range!(0.6678294, value, 0.00001);
 	/// let value = Math::ln(-10.0);
 	/// assert!(value.is_nan());
 	/// let value = Math::ln(0.0);
@@ -868,6 +1032,10 @@ impl Math {
 				x /= 2.0;
 				ln2_count += 1;
 			}
+			if x > core::f32::consts::SQRT_2 {
+				x /= 2.0;
+				ln2_count += 1;
+			}
 			
 			if x == 1.0 { return ln2_count as f32 * Math::LN2 + ln10_count as f32 * Math::LN10; }
 			
@@ -1055,6 +1223,37 @@ impl Math {
 	/// ```
 	pub fn min_max(a: f32, b: f32) -> (f32, f32) { (Math::min(a, b), Math::max(a, b)) }
 	
+	/// Computes the nth root of the given number
+	/// - **value**: The value to get the root of
+	/// - **n**: The degree of the root, where 2 is the square root and 3 is the cube root
+	/// 
+	/// **Returns**: Returns the nth root of the value. Returns `NaN` for even roots of negative numbers or when `n` is 0
+	/// #### Remarks
+	/// Odd roots of negative numbers are negative, so `nth_root(-8.0, 3)` returns -2.0
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Math,assert_range};
+	/// assert_range!(2.0, Math::nth_root(16.0, 4));
+	/// assert_range!(-2.0, Math::nth_root(-32.0, 5));
+	/// assert_range!(3.0, Math::nth_root(27.0, 3), 0.001);
+	/// assert_range!(1.5, Math::nth_root(2.25, 2));
+	/// assert_eq!(7.0, Math::nth_root(7.0, 1));
+	/// assert_eq!(0.0, Math::nth_root(0.0, 3));
+	/// assert!(Math::nth_root(-16.0, 4).is_nan());
+	/// assert!(Math::nth_root(16.0, 0).is_nan());
+	/// ```
+	pub fn nth_root(value: f32, n: u32) -> f32 {
+		if n == 0 { return f32::NAN; }
+		if n == 1 || value == 0.0 { return value; }
+		if value < 0.0 {
+			if n & 1 == 0 { return f32::NAN; }
+			
+			return -Math::pow(-value, 1.0 / n as f32);
+		}
+		
+		return Math::pow(value, 1.0 / n as f32);
+	}
+	
 	/// Raised the value by the power (as a floating point number)
 	/// - **value**: The value to raise with
 	/// - **power**: The power to raise by
@@ -1141,6 +1340,32 @@ impl Math {
 	/// ```
 	pub fn rad2deg(radians: f32) -> f32 { Math::RAD_TO_DEG * radians }
 	
+	/// Maps the value from one range into another range, clamping the value into the input range first
+	/// - **value**: The value to map
+	/// - **in_range**: The starting input range to map from, which can be reversed where the start is greater than the end
+	/// - **out_range**: The ending output range to map to
+	/// 
+	/// **Returns**: Returns the mapped value, which always lies within the output range
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Math,assert_range};
+	/// let value = Math::remap_clamped(5.0, 0.0..10.0, 0.0..1.0);
+	/// assert_range!(0.5, value);
+	/// let value = Math::remap_clamped(11.0, 0.0..10.0, 0.0..1.0);
+	/// assert_range!(1.0, value);
+	/// let value = Math::remap_clamped(-5.0, 0.0..10.0, 0.0..1.0);
+	/// assert_range!(0.0, value);
+	/// let value = Math::remap_clamped(2.5, 10.0..0.0, 0.0..1.0);
+	/// assert_range!(0.75, value);
+	/// let value = Math::remap_clamped(20.0, 10.0..0.0, 0.0..1.0);
+	/// assert_range!(0.0, value);
+	/// ```
+	pub fn remap_clamped(value: f32, in_range: Range<f32>, out_range: Range<f32>) -> f32 {
+		let (min, max) = Math::min_max(in_range.start, in_range.end);
+		
+		return Math::map(Math::clamp(value, min, max), in_range, out_range);
+	}
+	
 	/// Repeats the value around the range, making sure it stays within the range
 	/// - **value**: The value to repeat
 	/// - **range**: The range to repeat around
@@ -1211,6 +1436,37 @@ impl Math {
 		}
 	}
 	
+	/// Rounds the given value to the nearest whole number, rounding ties to the nearest even number (also known as banker's rounding)
+	/// - **value**: The value to round with
+	/// 
+	/// **Returns**: Returns the rounded value
+	/// #### Remarks
+	/// Unlike [`Math::round`], which rounds ties away from zero, this does not bias the results upwards when
+	/// rounding many values, which makes it useful for statistics and finances
+	/// #### Examples
+	/// ```
+	/// # use mathx::Math;
+	/// assert_eq!(0.0, Math::round_half_to_even(0.5));
+	/// assert_eq!(2.0, Math::round_half_to_even(1.5));
+	/// assert_eq!(2.0, Math::round_half_to_even(2.5));
+	/// assert_eq!(4.0, Math::round_half_to_even(3.5));
+	/// assert_eq!(0.0, Math::round_half_to_even(-0.5));
+	/// assert_eq!(-2.0, Math::round_half_to_even(-2.5));
+	/// assert_eq!(-4.0, Math::round_half_to_even(-3.5));
+	/// assert_eq!(3.0, Math::round_half_to_even(2.6));
+	/// assert_eq!(-5.0, Math::round_half_to_even(-5.45));
+	/// ```
+	pub fn round_half_to_even(value: f32) -> f32 {
+		let floor = Math::floor(value);
+		let difference = value - floor;
+		
+		if difference < 0.5 { return floor; }
+		if difference > 0.5 { return floor + 1.0; }
+		if Math::fract(0.5 * floor) == 0.0 { return floor; }
+		
+		return floor + 1.0;
+	}
+	
 	/// Rounds the value up to the given amount of digits past the decimal
 	/// - **value**: The value to round with
 	/// - **digits**: The digit past the decimal to round to, must be between -15 and 15
@@ -1254,6 +1510,28 @@ impl Math {
 		return truncated / pow10;
 	}
 	
+	/// Computes a sawtooth wave that rises from -1.0 to 1.0 over each period before jumping back down
+	/// - **t**: The time to sample the wave at
+	/// - **period**: The length of time of a single cycle of the wave
+	/// 
+	/// **Returns**: Returns the value of the wave between -1.0 and 1.0, or 0.0 if the period is 0.0
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Math,assert_range};
+	/// assert_range!(-1.0, Math::sawtooth(0.0, 2.0));
+	/// assert_range!(-0.5, Math::sawtooth(0.5, 2.0));
+	/// assert_range!(0.0, Math::sawtooth(1.0, 2.0));
+	/// assert_range!(0.5, Math::sawtooth(1.5, 2.0));
+	/// assert_range!(-1.0, Math::sawtooth(2.0, 2.0));
+	/// assert_range!(0.5, Math::sawtooth(-0.5, 2.0));
+	/// assert_eq!(0.0, Math::sawtooth(1.5, 0.0));
+	/// ```
+	pub fn sawtooth(t: f32, period: f32) -> f32 {
+		if period == 0.0 { return 0.0; }
+		
+		return 2.0 * Math::fract(t / period) - 1.0;
+	}
+	
 	/// Computes the secant of the given angle in radians
 	/// - **angle**: The given angle to compute the secant with in radians
 	/// 
@@ -1494,6 +1772,50 @@ impl Math {
 		}
 	}
 	
+	/// Smooths a value towards a desired goal over time, like a critically damped spring that never overshoots
+	/// - **current**: The current value
+	/// - **target**: The value to try to reach
+	/// - **velocity**: The current velocity of the value, which gets updated to the new velocity
+	/// - **smooth_time**: The time (in seconds) it will roughly take to reach the target
+	/// - **delta_time**: The time between frames
+	/// 
+	/// **Returns**: Returns the value that is closer towards the target
+	/// #### Remarks
+	/// The same velocity should be passed in every frame so that the motion stays smooth.
+	/// See [`Vector3::smooth_damp`](crate::Vector3::smooth_damp) for the vector version, which also limits the speed
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Math,assert_range};
+	/// let mut value = 0.0;
+	/// let mut velocity = 0.0;
+	/// for _ in 0..300 {
+	/// 	let next = Math::smooth_damp(value, 10.0, &mut velocity, 0.3, 1.0 / 60.0);
+	/// 	assert!(next >= value && next <= 10.0);
+	/// 	value = next;
+	/// }
+	/// assert_range!(10.0, value, 0.001);
+	/// let value = Math::smooth_damp(5.0, 5.0, &mut 0.0, 0.3, 0.0);
+	/// assert_eq!(5.0, value);
+	/// ```
+	pub fn smooth_damp(current: f32, target: f32, velocity: &mut f32, smooth_time: f32, delta_time: f32) -> f32 {
+		let smooth_time = Math::max(0.0001, smooth_time);
+		let omega = 2.0 / smooth_time;
+		let x = omega * delta_time;
+		let exp = 1.0 / (1.0 + x + 0.48 * x * x + 0.235 * x * x * x);
+		let change = current - target;
+		let temp = (*velocity + omega * change) * delta_time;
+		let result = target + (change + temp) * exp;
+		
+		*velocity = (*velocity - omega * temp) * exp;
+		
+		if (target - current > 0.0) == (result > target) {
+			*velocity = 0.0;
+			return target;
+		}
+		
+		return result;
+	}
+	
 	/// Computes a smooth Hermite interpolation that returns a number between 0.0 and 1.0
 	/// - **value**: The value for the interpolation, where `left_edge` &lt; `value` &lt; `right_edge`
 	/// - **left_edge**: The leftmost edge to where 0.0 would start at
@@ -1518,6 +1840,58 @@ impl Math {
 		return y * y * (3.0 - 2.0 * y);
 	}
 	
+	/// Solves the quadratic equation `a * x^2 + b * x + c = 0` for its real roots
+	/// - **a**: The coefficient of the squared term
+	/// - **b**: The coefficient of the linear term
+	/// - **c**: The constant term
+	/// 
+	/// **Returns**: Returns the two real roots sorted from smallest to largest, which are equal for a double root.
+	/// Returns `None` if there are no real roots
+	/// #### Remarks
+	/// If `a` is 0, the equation is solved as the linear equation `b * x + c = 0` and its single root is returned twice.
+	/// The roots are found in a way that avoids losing precision when `b * b` is much larger than `4 * a * c`
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Math,assert_range};
+	/// let (x1, x2) = Math::solve_quadratic(1.0, -3.0, 2.0).unwrap();
+	/// assert_range!(1.0, x1);
+	/// assert_range!(2.0, x2);
+	/// let (x1, x2) = Math::solve_quadratic(-2.0, 0.0, 8.0).unwrap();
+	/// assert_range!(-2.0, x1);
+	/// assert_range!(2.0, x2);
+	/// let (x1, x2) = Math::solve_quadratic(1.0, 2.0, 1.0).unwrap();
+	/// assert_range!(-1.0, x1);
+	/// assert_range!(-1.0, x2);
+	/// assert_eq!(None, Math::solve_quadratic(1.0, 0.0, 1.0));
+	/// let (x1, x2) = Math::solve_quadratic(0.0, 2.0, -3.0).unwrap();
+	/// assert_range!(1.5, x1);
+	/// assert_range!(1.5, x2);
+	/// assert_eq!(None, Math::solve_quadratic(0.0, 0.0, 1.0));
+	/// let (x1, x2) = Math::solve_quadratic(1.0, 10000.0, 1.0).unwrap();
+	/// assert_range!(-9999.9999, x1, 0.01);
+	/// assert_range!(-0.0001, x2, 0.0000001);
+	/// ```
+	pub fn solve_quadratic(a: f32, b: f32, c: f32) -> Option<(f32, f32)> {
+		if a == 0.0 {
+			if b == 0.0 { return None; }
+			
+			let root = -c / b;
+			
+			return Some((root, root));
+		}
+		
+		let discriminant = b * b - 4.0 * a * c;
+		
+		if discriminant < 0.0 { return None; }
+		
+		let sign = if b < 0.0 { -1.0 } else { 1.0 };
+		let q = -0.5 * (b + sign * Math::sqrt(discriminant));
+		
+		if q == 0.0 { return Some((0.0, 0.0)); }
+		
+		return Some(Math::min_max(q / a, c / q));
+	}
+	
 	/// Gets the square root of the given number
 	/// - **value**: The number to square root
 	/// 
@@ -1555,6 +1929,23 @@ impl Math {
 		}
 	}
 	
+	/// Gets a step function from the edge, the same as the `step` function found in shaders
+	/// - **edge**: The edge where the step happens
+	/// - **value**: The value to check against the edge
+	/// 
+	/// **Returns**: Returns 0.0 if the value is less than the edge, otherwise returns 1.0
+	/// #### Examples
+	/// ```
+	/// # use mathx::Math;
+	/// let value = Math::step(0.5, 0.5);
+	/// assert_eq!(1.0, value);
+	/// let value = Math::step(0.5, 0.4999);
+	/// assert_eq!(0.0, value);
+	/// let value = Math::step(0.5, 0.5001);
+	/// assert_eq!(1.0, value);
+	/// ```
+	pub fn step(edge: f32, value: f32) -> f32 { if value < edge { 0.0 } else { 1.0 } }
+	
 	/// Gets the tangent  of the angle in radians
 	/// - **angle**: The angle to compute the tangent with in radians
 	/// 
@@ -1639,6 +2030,31 @@ impl Math {
 		}
 	}
 	
+	/// Computes a triangle wave that rises from 0.0 to 1.0, falls to -1.0, and rises back to 0.0 over each period
+	/// - **t**: The time to sample the wave at
+	/// - **period**: The length of time of a single cycle of the wave
+	/// 
+	/// **Returns**: Returns the value of the wave between -1.0 and 1.0, or 0.0 if the period is 0.0
+	/// #### Remarks
+	/// The wave lines up with [`Math::sin`], peaking at a quarter of the period and bottoming out at three quarters of the period
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Math,assert_range};
+	/// assert_range!(0.0, Math::triangle_wave(0.0, 2.0));
+	/// assert_range!(1.0, Math::triangle_wave(0.5, 2.0));
+	/// assert_range!(0.0, Math::triangle_wave(1.0, 2.0));
+	/// assert_range!(-1.0, Math::triangle_wave(1.5, 2.0));
+	/// assert_range!(0.0, Math::triangle_wave(2.0, 2.0));
+	/// assert_range!(0.5, Math::triangle_wave(0.25, 2.0));
+	/// assert_range!(-1.0, Math::triangle_wave(-0.5, 2.0));
+	/// assert_eq!(0.0, Math::triangle_wave(0.5, 0.0));
+	/// ```
+	pub fn triangle_wave(t: f32, period: f32) -> f32 {
+		if period == 0.0 { return 0.0; }
+		
+		return 4.0 * Math::abs(Math::fract(t / period - 0.25) - 0.5) - 1.0;
+	}
+	
 	/// Truncates the value of the floating point number
 	/// - **value**: The number to truncate
 	/// 
@@ -1661,6 +2077,73 @@ impl Math {
 			(value as i32) as f32
 		}
 	}
+	
+	/// Wraps the angle so that it stays within -π and π
+	/// - **radians**: The angle in radians to wrap
+	/// 
+	/// **Returns**: Returns the wrapped angle in radians
+	/// #### Remarks
+	/// Angles that land exactly on the edge of the range can be returned as either -π or π
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Math,assert_range};
+	/// let value = Math::wrap_angle(3.0 * Math::PI);
+	/// assert_range!(Math::PI, Math::abs(value), 0.0001);
+	/// let value = Math::wrap_angle(-3.0 * Math::PI);
+	/// assert_range!(Math::PI, Math::abs(value), 0.0001);
+	/// let value = Math::wrap_angle(Math::PI_OVER_4);
+	/// assert_eq!(Math::PI_OVER_4, value);
+	/// let value = Math::wrap_angle(Math::TWO_PI + 1.0);
+	/// assert_range!(1.0, value, 0.0001);
+	/// let value = Math::wrap_angle(-Math::TWO_PI - 1.0);
+	/// assert_range!(-1.0, value, 0.0001);
+	/// ```
+	pub fn wrap_angle(radians: f32) -> f32 {
+		if (-Math::PI..=Math::PI).contains(&radians) { return radians; }
+		
+		return Math::TWO_PI * Math::fract((radians + Math::PI) / Math::TWO_PI) - Math::PI;
+	}
+	
+	/// Wraps the angle so that it stays within -180 and 180 degrees
+	/// - **degrees**: The angle in degrees to wrap
+	/// 
+	/// **Returns**: Returns the wrapped angle in degrees
+	/// #### Remarks
+	/// Angles that land exactly on the edge of the range can be returned as either -180 or 180
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Math,assert_range};
+	/// let value = Math::wrap_angle_deg(540.0);
+	/// assert_range!(180.0, Math::abs(value), 0.001);
+	/// let value = Math::wrap_angle_deg(-540.0);
+	/// assert_range!(180.0, Math::abs(value), 0.001);
+	/// let value = Math::wrap_angle_deg(45.0);
+	/// assert_eq!(45.0, value);
+	/// let value = Math::wrap_angle_deg(370.0);
+	/// assert_range!(10.0, value, 0.001);
+	/// let value = Math::wrap_angle_deg(-190.0);
+	/// assert_range!(170.0, value, 0.001);
+	/// ```
+	pub fn wrap_angle_deg(degrees: f32) -> f32 {
+		if (-180.0..=180.0).contains(&degrees) { return degrees; }
+		
+		return 360.0 * Math::fract((degrees + 180.0) / 360.0) - 180.0;
+	}
+	
+	/// Wraps the angle in degrees so that it stays within 0 and 360 degrees, where 360 wraps back to 0
+	/// - **degrees**: The angle in degrees to wrap
+	/// 
+	/// **Returns**: Returns the wrapped angle in degrees
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Math,assert_range};
+	/// assert_range!(10.0, Math::wrap_degrees(370.0), 0.001);
+	/// assert_range!(330.0, Math::wrap_degrees(-30.0), 0.001);
+	/// assert_range!(0.0, Math::wrap_degrees(720.0));
+	/// assert_eq!(45.0, Math::wrap_degrees(45.0));
+	/// assert!(Math::wrap_degrees(-1e-5) < 360.0);
+	/// ```
+	pub fn wrap_degrees(degrees: f32) -> f32 { Math::clamp_wrapped(degrees, 0.0..360.0) }
 }
 
 // Private Functions
@@ -1704,12 +2187,80 @@ impl Math {
 		}
 	}
 	
+	/// Runs the cordic algorithm in vectoring mode, rotating the vector onto the positive x-axis
+	/// - **x**: The x value of the vector to rotate
+	/// - **y**: The y value of the vector to rotate
+	/// 
+	/// **Returns**: Returns the angle of the vector from the positive x-axis and the magnitude of the vector (respectively) as a tuple
+	#[cfg(feature = "no_std")]
+	pub(self) fn cordic_vectoring(x: f32, y: f32) -> (f32, f32) {
+		const ITERATIONS: i32 = 28;
+		
+		if x.is_nan() || y.is_nan() { return (f32::NAN, f32::NAN); }
+		if y == 0.0 {
+			let angle = if x.is_sign_negative() {
+				if y.is_sign_negative() { -Math::PI } else { Math::PI }
+			} else { y };
+			
+			return (angle, Math::abs(x));
+		}
+		
+		let scale = Math::max(Math::abs(x), Math::abs(y));
+		let (x, y) = if scale.is_infinite() {
+			(
+				if x.is_infinite() { Math::sign(x) } else { 0.0 },
+				if y.is_infinite() { Math::sign(y) } else { 0.0 }
+			)
+		} else { (x / scale, y / scale) };
+		let (mut x, mut y, mut angle) = if x < 0.0 {
+			(-x, -y, if y.is_sign_negative() { -Math::PI } else { Math::PI })
+		} else { (x, y, 0.0) };
+		let mut factor = 1.0;
+		
+		for i in 0..ITERATIONS {
+			let di = if y > 0.0 { 1.0 } else { -1.0 };
+			let new_x = x + (y * di * factor);
+			let new_y = y - (x * di * factor);
+			
+			x = new_x;
+			y = new_y;
+			angle += di * Math::get_atan_for_cordic(i);
+			factor *= 0.5;
+		}
+		
+		return (angle, scale * (0.60725293500888 * x));
+	}
+	
 	/// Negates the tuple, multiplying both components by -1
 	/// - **tuple**: The tuple to negate
 	/// 
 	/// **Returns**: Returns the negated tuple
 	#[cfg(feature = "no_std")]
 	pub(self) fn negate_tuple(tuple: (f32, f32)) -> (f32, f32) { (-tuple.0, -tuple.1) }
+	
+	/// Computes the series used by the Lanczos approximation of the gamma function (with g = 7)
+	/// - **x**: The value to compute the series for, which is one less than the gamma function's input
+	/// 
+	/// **Returns**: Returns the sum of the Lanczos series
+	pub(self) fn lanczos_sum(x: f32) -> f32 {
+		const COEFFICIENTS: [f32; 8] = [
+			676.5204,
+			-1259.1392,
+			771.3234,
+			-176.61504,
+			12.507343,
+			-0.1385711,
+			0.000009984369,
+			0.00000015056327,
+		];
+		let mut sum = 1.0;
+		
+		for (i, coefficient) in COEFFICIENTS.iter().enumerate() {
+			sum += coefficient / (x + (i + 1) as f32);
+		}
+		
+		return sum;
+	}
 }
 
 #[doc(hidden)]
@@ -1733,3 +2284,55 @@ macro_rules! assert_range_tuple2 {
 		assert_range_tuple2!($expected, $value, 0.0001);
 	};
 }
+
+#[cfg(all(test, feature = "no_std"))]
+mod tests {
+	extern crate std;
+	
+	use super::Math;
+	
+	fn assert_angle(expected: f32, actual: f32, y: f32, x: f32) {
+		let diff = Math::abs(expected - actual);
+		
+		assert!(
+			diff < 0.00001 || Math::abs(Math::TWO_PI - diff) < 0.00001,
+			"atan2({}, {}): expected {}, got {}", y, x, expected, actual
+		);
+	}
+	
+	#[test]
+	fn cordic_atan2_and_hypot_match_std_in_every_quadrant() {
+		let magnitudes = [0.001, 0.5, 1.0, 3.0, 250.0, 1e20];
+		
+		for step in 0..=72 {
+			let angle = (step as f64 - 36.0) * 5.0f64.to_radians();
+			
+			for magnitude in magnitudes {
+				let x = (magnitude * angle.cos()) as f32;
+				let y = (magnitude * angle.sin()) as f32;
+				let expected = x.hypot(y);
+				
+				assert_angle(y.atan2(x), Math::atan2(y, x), y, x);
+				assert!(
+					Math::abs(expected - Math::hypot(x, y)) <= 0.00001 * expected,
+					"hypot({}, {}): expected {}, got {}", x, y, expected, Math::hypot(x, y)
+				);
+			}
+		}
+	}
+	
+	#[test]
+	fn cordic_atan2_matches_std_on_signed_zeros() {
+		let zeros = [0.0f32, -0.0];
+		
+		for y in zeros {
+			for x in [1.0f32, -1.0, 0.0, -0.0] {
+				let expected = y.atan2(x);
+				let actual = Math::atan2(y, x);
+				
+				assert_eq!(expected.is_sign_negative(), actual.is_sign_negative(), "atan2({}, {})", y, x);
+				assert!(Math::abs(expected - actual) < 0.00001, "atan2({}, {}): expected {}, got {}", y, x, expected, actual);
+			}
+		}
+	}
+}
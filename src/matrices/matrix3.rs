@@ -0,0 +1,338 @@
+
+use crate::{Math, Vector3};
+#[cfg(not(feature = "no_quaternions"))]
+use crate::Quaternion;
+use core::ops::Mul;
+use crate::impl_mul;
+
+/// A 3x3 matrix of column-major values, used for rotations and transforming normals
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy)]
+pub struct Matrix3 {
+	/// The values of the matrix, stored column by column
+	values: [f32; 9],
+}
+
+/// Constructors
+impl Matrix3 {
+	/// Creates a new 3x3 matrix
+	/// - **values**: The values of the matrix, stored column by column
+	/// 
+	/// **Returns**: Returns a new 3x3 matrix
+	/// #### Examples
+	/// ```
+	/// # use mathx::Matrix3;
+	/// let matrix = Matrix3::new([
+	/// 	1.0, 2.0, 3.0,
+	/// 	4.0, 5.0, 6.0,
+	/// 	7.0, 8.0, 9.0,
+	/// ]);
+	/// assert_eq!(2.0, matrix.get(1, 0));
+	/// assert_eq!(4.0, matrix.get(0, 1));
+	/// ```
+	pub fn new(values: [f32; 9]) -> Self { Matrix3 { values } }
+	
+	/// Creates a new 3x3 matrix from three column vectors
+	/// - **x**: The first column of the matrix
+	/// - **y**: The second column of the matrix
+	/// - **z**: The third column of the matrix
+	/// 
+	/// **Returns**: Returns a new 3x3 matrix
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Matrix3, Vector3};
+	/// let matrix = Matrix3::from_columns(Vector3::up(), Vector3::right(), Vector3::forward());
+	/// assert_eq!(Vector3::up(), matrix.transform(Vector3::right()));
+	/// assert_eq!(Vector3::right(), matrix.transform(Vector3::up()));
+	/// ```
+	pub fn from_columns(x: Vector3, y: Vector3, z: Vector3) -> Self {
+		Matrix3::new([
+			x.x(), x.y(), x.z(),
+			y.x(), y.y(), y.z(),
+			z.x(), z.y(), z.z(),
+		])
+	}
+	
+	/// Creates an identity matrix, which leaves everything it transforms unchanged
+	/// 
+	/// **Returns**: Returns an identity matrix
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Matrix3, Vector3};
+	/// let matrix = Matrix3::identity();
+	/// assert_eq!(Vector3::new(1.0, 2.0, 3.0), matrix.transform(Vector3::new(1.0, 2.0, 3.0)));
+	/// ```
+	pub fn identity() -> Self {
+		Matrix3::new([
+			1.0, 0.0, 0.0,
+			0.0, 1.0, 0.0,
+			0.0, 0.0, 1.0,
+		])
+	}
+	
+	/// Creates a rotation matrix over the given axis and angle
+	/// - **axis**: The axis that the matrix will rotate around
+	/// - **angle**: The angle in radians that the matrix will rotate around
+	/// 
+	/// **Returns**: Returns a rotation matrix
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Matrix3, Vector3, Math};
+	/// let matrix = Matrix3::from_axis_angle(Vector3::up(), Math::PI_OVER_2);
+	/// assert_eq!(Vector3::back(), matrix.transform(Vector3::right()));
+	/// assert_eq!(Vector3::up(), matrix.transform(Vector3::up()));
+	/// ```
+	pub fn from_axis_angle(axis: Vector3, angle: f32) -> Self {
+		let (sin, cos) = Math::sin_cos(angle);
+		let norm = axis.normalize();
+		let (x, y, z) = (norm.x(), norm.y(), norm.z());
+		let t = 1.0 - cos;
+		
+		return Matrix3::new([
+			t * x * x + cos, t * x * y + sin * z, t * x * z - sin * y,
+			t * x * y - sin * z, t * y * y + cos, t * y * z + sin * x,
+			t * x * z + sin * y, t * y * z - sin * x, t * z * z + cos,
+		]);
+	}
+	
+	/// Creates a rotation matrix from the given quaternion
+	/// - **rotation**: The rotation quaternion to create the matrix from
+	/// 
+	/// **Returns**: Returns a rotation matrix
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Matrix3, Quaternion, Vector3, Math};
+	/// let rotation = Quaternion::from_axis_angle(Vector3::new(1.0, 2.0, 3.0), 0.7);
+	/// let matrix = Matrix3::from_quaternion(rotation);
+	/// let point = Vector3::new(1.0, 2.0, 3.0);
+	/// assert_eq!(rotation * point, matrix.transform(point));
+	/// assert_eq!(Matrix3::from_axis_angle(Vector3::new(1.0, 2.0, 3.0), 0.7), matrix);
+	/// ```
+	#[cfg(not(feature = "no_quaternions"))]
+	pub fn from_quaternion(rotation: Quaternion) -> Self {
+		let (w, x, y, z) = (rotation.a(), rotation.b(), rotation.c(), rotation.d());
+		let (xx, yy, zz) = (x * x, y * y, z * z);
+		let (xy, xz, yz) = (x * y, x * z, y * z);
+		let (wx, wy, wz) = (w * x, w * y, w * z);
+		
+		return Matrix3::new([
+			1.0 - 2.0 * (yy + zz), 2.0 * (xy + wz), 2.0 * (xz - wy),
+			2.0 * (xy - wz), 1.0 - 2.0 * (xx + zz), 2.0 * (yz + wx),
+			2.0 * (xz + wy), 2.0 * (yz - wx), 1.0 - 2.0 * (xx + yy),
+		]);
+	}
+}
+
+/// Properties
+impl Matrix3 {
+	/// Gets the values of the matrix, stored column by column
+	/// 
+	/// **Returns**: Returns the column-major values of the matrix
+	/// #### Examples
+	/// ```
+	/// # use mathx::Matrix3;
+	/// let matrix = Matrix3::identity();
+	/// assert_eq!([1.0, 0.0, 0.0], matrix.values()[0..3]);
+	/// ```
+	pub fn values(&self) -> [f32; 9] { self.values }
+	
+	/// Gets the value at the given row and column
+	/// - **row**: The row of the value, from 0 to 2
+	/// - **column**: The column of the value, from 0 to 2
+	/// 
+	/// **Returns**: Returns the value at the given row and column
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Matrix3, Vector3};
+	/// let matrix = Matrix3::from_columns(Vector3::one(), Vector3::up(), Vector3::forward());
+	/// assert_eq!(1.0, matrix.get(2, 0));
+	/// assert_eq!(0.0, matrix.get(0, 2));
+	/// ```
+	pub fn get(&self, row: usize, column: usize) -> f32 { self.values[column * 3 + row] }
+	
+	/// Sets the value at the given row and column
+	/// - **row**: The row of the value, from 0 to 2
+	/// - **column**: The column of the value, from 0 to 2
+	/// - **value**: The value to set
+	/// #### Examples
+	/// ```
+	/// # use mathx::Matrix3;
+	/// let mut matrix = Matrix3::identity();
+	/// matrix.set(0, 2, 5.0);
+	/// assert_eq!(5.0, matrix.get(0, 2));
+	/// ```
+	pub fn set(&mut self, row: usize, column: usize, value: f32) { self.values[column * 3 + row] = value; }
+	
+	/// Gets the column at the given index as a 3D vector
+	/// - **index**: The index of the column, from 0 to 2
+	/// 
+	/// **Returns**: Returns the column at the given index
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Matrix3, Vector3};
+	/// let matrix = Matrix3::from_columns(Vector3::one(), Vector3::up(), Vector3::forward());
+	/// assert_eq!(Vector3::up(), matrix.column(1));
+	/// ```
+	pub fn column(&self, index: usize) -> Vector3 {
+		let start = index * 3;
+		
+		return Vector3::new(self.values[start], self.values[start + 1], self.values[start + 2]);
+	}
+}
+
+/// Public Methods
+impl Matrix3 {
+	/// Computes the determinant of the matrix
+	/// 
+	/// **Returns**: Returns the determinant of the matrix
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Matrix3, Vector3, Math, assert_range};
+	/// let matrix = Matrix3::from_columns(Vector3::new(2.0, 0.0, 0.0), Vector3::new(1.0, 3.0, 0.0), Vector3::new(5.0, 2.0, 4.0));
+	/// assert_eq!(24.0, matrix.determinant());
+	/// let matrix = Matrix3::from_axis_angle(Vector3::one(), 1.2);
+	/// assert_range!(1.0, matrix.determinant());
+	/// ```
+	pub fn determinant(&self) -> f32 {
+		self.column(0) * self.column(1).cross(self.column(2))
+	}
+	
+	/// Computes the inverse of the matrix
+	/// 
+	/// **Returns**: Returns the inverse of the matrix, or `None` if the matrix cannot be inverted
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Matrix3, Vector3};
+	/// let matrix = Matrix3::from_columns(Vector3::new(2.0, 0.0, 0.0), Vector3::new(1.0, 4.0, 0.0), Vector3::new(0.0, 2.0, 0.5));
+	/// assert_eq!(Matrix3::identity(), matrix * matrix.inverse().unwrap());
+	/// assert_eq!(Matrix3::identity(), matrix.inverse().unwrap() * matrix);
+	/// let singular = Matrix3::from_columns(Vector3::one(), Vector3::one(), Vector3::up());
+	/// assert_eq!(None, singular.inverse());
+	/// ```
+	pub fn inverse(&self) -> Option<Self> {
+		let (x, y, z) = (self.column(0), self.column(1), self.column(2));
+		let yz = y.cross(z);
+		let det = x * yz;
+		
+		if det == 0.0 { return None; }
+		
+		let inv_det = 1.0 / det;
+		
+		return Some(Matrix3::from_columns(
+			inv_det * yz,
+			inv_det * z.cross(x),
+			inv_det * x.cross(y)
+		).transpose());
+	}
+	
+	/// Multiplies the two matrices together
+	/// - **rhs**: The matrix to multiply with
+	/// 
+	/// **Returns**: Returns the multiplied matrix
+	/// #### Remarks
+	/// The right hand side is applied first, so `a.multiply(b)` transforms by `b` and then by `a`
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Matrix3, Vector3, Math};
+	/// let quarter = Matrix3::from_axis_angle(Vector3::up(), Math::PI_OVER_2);
+	/// let half = quarter.multiply(quarter);
+	/// assert_eq!(Vector3::left(), half.transform(Vector3::right()));
+	/// assert_eq!(Matrix3::identity(), half * half);
+	/// ```
+	pub fn multiply(self, rhs: Matrix3) -> Self {
+		let mut values = [0.0; 9];
+		
+		for column in 0..3 {
+			for row in 0..3 {
+				let mut sum = 0.0;
+				
+				for k in 0..3 {
+					sum += self.values[k * 3 + row] * rhs.values[column * 3 + k];
+				}
+				
+				values[column * 3 + row] = sum;
+			}
+		}
+		
+		return Matrix3::new(values);
+	}
+	
+	/// Transforms the given vector by the matrix
+	/// - **vector**: The vector to transform
+	/// 
+	/// **Returns**: Returns the transformed vector
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Matrix3, Vector3, Math};
+	/// let matrix = Matrix3::from_axis_angle(Vector3::up(), Math::PI_OVER_2);
+	/// assert_eq!(Vector3::back(), matrix.transform(Vector3::right()));
+	/// assert_eq!(Vector3::back(), matrix * Vector3::right());
+	/// ```
+	pub fn transform(&self, vector: Vector3) -> Vector3 {
+		vector.x() * self.column(0) + vector.y() * self.column(1) + vector.z() * self.column(2)
+	}
+	
+	/// Transposes the matrix, swapping the rows and columns
+	/// 
+	/// **Returns**: Returns the transposed matrix
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Matrix3, Vector3, Math};
+	/// let matrix = Matrix3::from_columns(Vector3::one(), Vector3::up(), Vector3::forward()).transpose();
+	/// assert_eq!(1.0, matrix.get(0, 2));
+	/// assert_eq!(0.0, matrix.get(2, 0));
+	/// let rotation = Matrix3::from_axis_angle(Vector3::one(), 0.5);
+	/// assert_eq!(rotation.inverse().unwrap(), rotation.transpose());
+	/// ```
+	pub fn transpose(self) -> Self {
+		let mut values = [0.0; 9];
+		
+		for column in 0..3 {
+			for row in 0..3 {
+				values[row * 3 + column] = self.values[column * 3 + row];
+			}
+		}
+		
+		return Matrix3::new(values);
+	}
+}
+
+impl Default for Matrix3 {
+	/// Creates an identity matrix
+	/// #### Examples
+	/// ```
+	/// # use mathx::Matrix3;
+	/// assert_eq!(Matrix3::identity(), Matrix3::default());
+	/// ```
+	fn default() -> Self { Matrix3::identity() }
+}
+
+unsafe impl Send for Matrix3 {}
+unsafe impl Sync for Matrix3 {}
+
+// Equates
+impl Eq for Matrix3 {}
+impl PartialEq for Matrix3 {
+	fn eq(&self, other: &Self) -> bool {
+		self.values.iter().zip(other.values.iter()).all(|(a, b)| Math::approx(*a, *b))
+	}
+}
+
+// Display
+#[cfg(not(feature = "no_std"))]
+impl std::fmt::Display for Matrix3 {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let m = &self.values;
+		
+		f.write_str(&format!(
+			"[{}, {}, {}]\n[{}, {}, {}]\n[{}, {}, {}]",
+			m[0], m[3], m[6],
+			m[1], m[4], m[7],
+			m[2], m[5], m[8]
+		))
+	}
+}
+
+// Arithmetic
+impl_mul!(Matrix3, Matrix3 => Matrix3: multiply);
+impl_mul!(Matrix3, Vector3 => Vector3: transform);
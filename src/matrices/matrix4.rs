@@ -0,0 +1,391 @@
+
+use crate::{Math, Vector3};
+#[cfg(not(feature = "no_quaternions"))]
+use crate::Quaternion;
+use core::ops::Mul;
+use crate::impl_mul;
+
+/// A 4x4 matrix of column-major values, used for 3D transforms
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy)]
+pub struct Matrix4 {
+	/// The values of the matrix, stored column by column
+	values: [f32; 16],
+}
+
+/// Constructors
+impl Matrix4 {
+	/// Creates a new 4x4 matrix
+	/// - **values**: The values of the matrix, stored column by column
+	/// 
+	/// **Returns**: Returns a new 4x4 matrix
+	/// #### Examples
+	/// ```
+	/// # use mathx::Matrix4;
+	/// let matrix = Matrix4::new([
+	/// 	1.0, 0.0, 0.0, 0.0,
+	/// 	0.0, 1.0, 0.0, 0.0,
+	/// 	0.0, 0.0, 1.0, 0.0,
+	/// 	2.0, 3.0, 4.0, 1.0,
+	/// ]);
+	/// assert_eq!(2.0, matrix.get(0, 3));
+	/// assert_eq!(3.0, matrix.get(1, 3));
+	/// assert_eq!(4.0, matrix.get(2, 3));
+	/// ```
+	pub fn new(values: [f32; 16]) -> Self { Matrix4 { values } }
+	
+	/// Creates an identity matrix, which leaves everything it transforms unchanged
+	/// 
+	/// **Returns**: Returns an identity matrix
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Matrix4, Vector3};
+	/// let matrix = Matrix4::identity();
+	/// assert_eq!(Vector3::new(1.0, 2.0, 3.0), matrix.transform_point(Vector3::new(1.0, 2.0, 3.0)));
+	/// ```
+	pub fn identity() -> Self {
+		Matrix4::new([
+			1.0, 0.0, 0.0, 0.0,
+			0.0, 1.0, 0.0, 0.0,
+			0.0, 0.0, 1.0, 0.0,
+			0.0, 0.0, 0.0, 1.0,
+		])
+	}
+	
+	/// Creates a matrix that translates points by the given offset
+	/// - **translation**: The offset to translate by
+	/// 
+	/// **Returns**: Returns a translation matrix
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Matrix4, Vector3};
+	/// let matrix = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0));
+	/// assert_eq!(Vector3::new(2.0, 2.0, 3.0), matrix.transform_point(Vector3::right()));
+	/// assert_eq!(Vector3::right(), matrix.transform_vector(Vector3::right()));
+	/// ```
+	pub fn from_translation(translation: Vector3) -> Self {
+		Matrix4::new([
+			1.0, 0.0, 0.0, 0.0,
+			0.0, 1.0, 0.0, 0.0,
+			0.0, 0.0, 1.0, 0.0,
+			translation.x(), translation.y(), translation.z(), 1.0,
+		])
+	}
+	
+	/// Creates a matrix that scales each axis by the given amount
+	/// - **scale**: The amount to scale each axis by
+	/// 
+	/// **Returns**: Returns a scaling matrix
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Matrix4, Vector3};
+	/// let matrix = Matrix4::from_scale(Vector3::new(2.0, 3.0, 4.0));
+	/// assert_eq!(Vector3::new(2.0, 3.0, 4.0), matrix.transform_point(Vector3::one()));
+	/// ```
+	pub fn from_scale(scale: Vector3) -> Self {
+		Matrix4::new([
+			scale.x(), 0.0, 0.0, 0.0,
+			0.0, scale.y(), 0.0, 0.0,
+			0.0, 0.0, scale.z(), 0.0,
+			0.0, 0.0, 0.0, 1.0,
+		])
+	}
+	
+	/// Creates a rotation matrix from the given quaternion
+	/// - **rotation**: The rotation quaternion to create the matrix from
+	/// 
+	/// **Returns**: Returns a rotation matrix
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Matrix4, Quaternion, Vector3, Math};
+	/// let rotation = Quaternion::from_axis_angle(Vector3::up(), Math::PI_OVER_2);
+	/// let matrix = Matrix4::from_quaternion(rotation);
+	/// let point = Vector3::new(1.0, 2.0, 3.0);
+	/// assert_eq!(rotation * point, matrix.transform_point(point));
+	/// ```
+	#[cfg(not(feature = "no_quaternions"))]
+	pub fn from_quaternion(rotation: Quaternion) -> Self {
+		let (w, x, y, z) = (rotation.a(), rotation.b(), rotation.c(), rotation.d());
+		let (xx, yy, zz) = (x * x, y * y, z * z);
+		let (xy, xz, yz) = (x * y, x * z, y * z);
+		let (wx, wy, wz) = (w * x, w * y, w * z);
+		
+		return Matrix4::new([
+			1.0 - 2.0 * (yy + zz), 2.0 * (xy + wz), 2.0 * (xz - wy), 0.0,
+			2.0 * (xy - wz), 1.0 - 2.0 * (xx + zz), 2.0 * (yz + wx), 0.0,
+			2.0 * (xz + wy), 2.0 * (yz - wx), 1.0 - 2.0 * (xx + yy), 0.0,
+			0.0, 0.0, 0.0, 1.0,
+		]);
+	}
+}
+
+/// Properties
+impl Matrix4 {
+	/// Gets the values of the matrix, stored column by column
+	/// 
+	/// **Returns**: Returns the column-major values of the matrix
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Matrix4, Vector3};
+	/// let matrix = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0));
+	/// assert_eq!([1.0, 2.0, 3.0, 1.0], matrix.values()[12..16]);
+	/// ```
+	pub fn values(&self) -> [f32; 16] { self.values }
+	
+	/// Gets the value at the given row and column
+	/// - **row**: The row of the value, from 0 to 3
+	/// - **column**: The column of the value, from 0 to 3
+	/// 
+	/// **Returns**: Returns the value at the given row and column
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Matrix4, Vector3};
+	/// let matrix = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0));
+	/// assert_eq!(2.0, matrix.get(1, 3));
+	/// assert_eq!(0.0, matrix.get(3, 1));
+	/// ```
+	pub fn get(&self, row: usize, column: usize) -> f32 { self.values[column * 4 + row] }
+	
+	/// Sets the value at the given row and column
+	/// - **row**: The row of the value, from 0 to 3
+	/// - **column**: The column of the value, from 0 to 3
+	/// - **value**: The value to set
+	/// #### Examples
+	/// ```
+	/// # use mathx::Matrix4;
+	/// let mut matrix = Matrix4::identity();
+	/// matrix.set(0, 3, 5.0);
+	/// assert_eq!(5.0, matrix.get(0, 3));
+	/// ```
+	pub fn set(&mut self, row: usize, column: usize, value: f32) { self.values[column * 4 + row] = value; }
+}
+
+/// Public Methods
+impl Matrix4 {
+	/// Computes the determinant of the matrix
+	/// 
+	/// **Returns**: Returns the determinant of the matrix
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Matrix4, Vector3};
+	/// let matrix = Matrix4::from_scale(Vector3::new(2.0, 3.0, 4.0));
+	/// assert_eq!(24.0, matrix.determinant());
+	/// let matrix = Matrix4::from_translation(Vector3::one()) * matrix;
+	/// assert_eq!(24.0, matrix.determinant());
+	/// ```
+	pub fn determinant(&self) -> f32 {
+		let (s, c) = self.sub_determinants();
+		
+		return s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0];
+	}
+	
+	/// Computes the inverse of the matrix
+	/// 
+	/// **Returns**: Returns the inverse of the matrix, or `None` if the matrix cannot be inverted
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Matrix4, Vector3};
+	/// let matrix = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0)) * Matrix4::from_scale(Vector3::new(2.0, 4.0, 0.5));
+	/// let inverse = matrix.inverse().unwrap();
+	/// assert_eq!(Matrix4::identity(), matrix * inverse);
+	/// assert_eq!(Vector3::one(), inverse.transform_point(matrix.transform_point(Vector3::one())));
+	/// assert_eq!(None, Matrix4::from_scale(Vector3::new(1.0, 0.0, 1.0)).inverse());
+	/// assert!(Matrix4::from_scale(Vector3::new(0.005, 0.005, 0.005)).inverse().is_some());
+	/// ```
+	pub fn inverse(&self) -> Option<Self> {
+		let (s, c) = self.sub_determinants();
+		let det = s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0];
+		
+		if det == 0.0 { return None; }
+		
+		let m = &self.values;
+		let inv_det = 1.0 / det;
+		
+		return Some(Matrix4::new([
+			(m[5] * c[5] - m[6] * c[4] + m[7] * c[3]) * inv_det,
+			(-m[1] * c[5] + m[2] * c[4] - m[3] * c[3]) * inv_det,
+			(m[13] * s[5] - m[14] * s[4] + m[15] * s[3]) * inv_det,
+			(-m[9] * s[5] + m[10] * s[4] - m[11] * s[3]) * inv_det,
+			(-m[4] * c[5] + m[6] * c[2] - m[7] * c[1]) * inv_det,
+			(m[0] * c[5] - m[2] * c[2] + m[3] * c[1]) * inv_det,
+			(-m[12] * s[5] + m[14] * s[2] - m[15] * s[1]) * inv_det,
+			(m[8] * s[5] - m[10] * s[2] + m[11] * s[1]) * inv_det,
+			(m[4] * c[4] - m[5] * c[2] + m[7] * c[0]) * inv_det,
+			(-m[0] * c[4] + m[1] * c[2] - m[3] * c[0]) * inv_det,
+			(m[12] * s[4] - m[13] * s[2] + m[15] * s[0]) * inv_det,
+			(-m[8] * s[4] + m[9] * s[2] - m[11] * s[0]) * inv_det,
+			(-m[4] * c[3] + m[5] * c[1] - m[6] * c[0]) * inv_det,
+			(m[0] * c[3] - m[1] * c[1] + m[2] * c[0]) * inv_det,
+			(-m[12] * s[3] + m[13] * s[1] - m[14] * s[0]) * inv_det,
+			(m[8] * s[3] - m[9] * s[1] + m[10] * s[0]) * inv_det,
+		]));
+	}
+	
+	/// Multiplies the two matrices together
+	/// - **rhs**: The matrix to multiply with
+	/// 
+	/// **Returns**: Returns the multiplied matrix
+	/// #### Remarks
+	/// The right hand side is applied first, so `a.multiply(b)` transforms by `b` and then by `a`
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Matrix4, Vector3};
+	/// let translation = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0));
+	/// let scale = Matrix4::from_scale(Vector3::new(2.0, 2.0, 2.0));
+	/// let point = Vector3::one();
+	/// assert_eq!(Vector3::new(3.0, 4.0, 5.0), translation.multiply(scale).transform_point(point));
+	/// assert_eq!(Vector3::new(4.0, 6.0, 8.0), (scale * translation).transform_point(point));
+	/// ```
+	pub fn multiply(self, rhs: Matrix4) -> Self {
+		let mut values = [0.0; 16];
+		
+		for column in 0..4 {
+			for row in 0..4 {
+				let mut sum = 0.0;
+				
+				for k in 0..4 {
+					sum += self.values[k * 4 + row] * rhs.values[column * 4 + k];
+				}
+				
+				values[column * 4 + row] = sum;
+			}
+		}
+		
+		return Matrix4::new(values);
+	}
+	
+	/// Transforms the given point, applying translation and the perspective divide
+	/// - **point**: The point to transform
+	/// 
+	/// **Returns**: Returns the transformed point
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Matrix4, Vector3};
+	/// let matrix = Matrix4::from_translation(Vector3::up()) * Matrix4::from_scale(Vector3::new(3.0, 3.0, 3.0));
+	/// assert_eq!(Vector3::new(3.0, 4.0, 0.0), matrix.transform_point(Vector3::new(1.0, 1.0, 0.0)));
+	/// ```
+	pub fn transform_point(&self, point: Vector3) -> Vector3 {
+		let m = &self.values;
+		let (x, y, z) = (point.x(), point.y(), point.z());
+		let w = m[3] * x + m[7] * y + m[11] * z + m[15];
+		let result = Vector3::new(
+			m[0] * x + m[4] * y + m[8] * z + m[12],
+			m[1] * x + m[5] * y + m[9] * z + m[13],
+			m[2] * x + m[6] * y + m[10] * z + m[14]
+		);
+		
+		if w == 0.0 || w == 1.0 { return result; }
+		
+		return result / w;
+	}
+	
+	/// Transforms the given direction, ignoring any translation
+	/// - **vector**: The direction to transform
+	/// 
+	/// **Returns**: Returns the transformed direction
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Matrix4, Vector3};
+	/// let matrix = Matrix4::from_translation(Vector3::up()) * Matrix4::from_scale(Vector3::new(3.0, 3.0, 3.0));
+	/// assert_eq!(Vector3::new(3.0, 3.0, 0.0), matrix.transform_vector(Vector3::new(1.0, 1.0, 0.0)));
+	/// ```
+	pub fn transform_vector(&self, vector: Vector3) -> Vector3 {
+		let m = &self.values;
+		let (x, y, z) = (vector.x(), vector.y(), vector.z());
+		
+		return Vector3::new(
+			m[0] * x + m[4] * y + m[8] * z,
+			m[1] * x + m[5] * y + m[9] * z,
+			m[2] * x + m[6] * y + m[10] * z
+		);
+	}
+	
+	/// Transposes the matrix, swapping the rows and columns
+	/// 
+	/// **Returns**: Returns the transposed matrix
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Matrix4, Vector3};
+	/// let matrix = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0)).transpose();
+	/// assert_eq!(2.0, matrix.get(3, 1));
+	/// assert_eq!(0.0, matrix.get(1, 3));
+	/// ```
+	pub fn transpose(self) -> Self {
+		let mut values = [0.0; 16];
+		
+		for column in 0..4 {
+			for row in 0..4 {
+				values[row * 4 + column] = self.values[column * 4 + row];
+			}
+		}
+		
+		return Matrix4::new(values);
+	}
+}
+
+/// Private Methods
+impl Matrix4 {
+	/// Computes the 2x2 sub-determinants of the top and bottom halves used by the determinant and inverse
+	fn sub_determinants(&self) -> ([f32; 6], [f32; 6]) {
+		let m = &self.values;
+		
+		return (
+			[
+				m[0] * m[5] - m[4] * m[1],
+				m[0] * m[6] - m[4] * m[2],
+				m[0] * m[7] - m[4] * m[3],
+				m[1] * m[6] - m[5] * m[2],
+				m[1] * m[7] - m[5] * m[3],
+				m[2] * m[7] - m[6] * m[3],
+			],
+			[
+				m[8] * m[13] - m[12] * m[9],
+				m[8] * m[14] - m[12] * m[10],
+				m[8] * m[15] - m[12] * m[11],
+				m[9] * m[14] - m[13] * m[10],
+				m[9] * m[15] - m[13] * m[11],
+				m[10] * m[15] - m[14] * m[11],
+			]
+		);
+	}
+}
+
+impl Default for Matrix4 {
+	/// Creates an identity matrix
+	/// #### Examples
+	/// ```
+	/// # use mathx::Matrix4;
+	/// assert_eq!(Matrix4::identity(), Matrix4::default());
+	/// ```
+	fn default() -> Self { Matrix4::identity() }
+}
+
+unsafe impl Send for Matrix4 {}
+unsafe impl Sync for Matrix4 {}
+
+// Equates
+impl Eq for Matrix4 {}
+impl PartialEq for Matrix4 {
+	fn eq(&self, other: &Self) -> bool {
+		self.values.iter().zip(other.values.iter()).all(|(a, b)| Math::approx(*a, *b))
+	}
+}
+
+// Display
+#[cfg(not(feature = "no_std"))]
+impl std::fmt::Display for Matrix4 {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let m = &self.values;
+		
+		f.write_str(&format!(
+			"[{}, {}, {}, {}]\n[{}, {}, {}, {}]\n[{}, {}, {}, {}]\n[{}, {}, {}, {}]",
+			m[0], m[4], m[8], m[12],
+			m[1], m[5], m[9], m[13],
+			m[2], m[6], m[10], m[14],
+			m[3], m[7], m[11], m[15]
+		))
+	}
+}
+
+// Arithmetic
+impl_mul!(Matrix4, Matrix4 => Matrix4: multiply);
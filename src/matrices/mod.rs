@@ -0,0 +1,6 @@
+
+mod matrix3;
+pub use matrix3::Matrix3;
+
+mod matrix4;
+pub use matrix4::Matrix4;
@@ -200,6 +200,8 @@ impl Plane {
 	/// let plane = Plane::new(Vector3::new(1.0, -2.0, 3.0), 3.0);
 	/// let point = plane.closest_point(Vector3::one());
 	/// assert_eq!(Vector3::new(0.05535913, 2.889282, -1.833922), point);
+	/// let plane = Plane::xz_plane();
+	/// assert_eq!(Vector3::new(3.0, 0.0, -2.0), plane.closest_point(Vector3::new(3.0, 4.0, -2.0)));
 	/// ```
 	pub fn closest_point(self, point: Vector3) -> Vector3 {
 		point - self.normal * self.distance_to_point(point)
@@ -209,12 +211,17 @@ impl Plane {
 	/// - **point**: The point to find the distance from the plane
 	/// 
 	/// **Returns**: Returns the distance from the point to the plane
+	/// #### Remarks
+	/// The distance is signed, being positive on the side the normal faces and negative on the other side
 	/// #### Examples
 	/// ```
 	/// # use mathx::{Vector3, Plane, Math, assert_range};
 	/// let plane = Plane::new(Vector3::new(1.0, -2.0, 3.0), 3.0);
 	/// let distance = plane.distance_to_point(Vector3::one());
-	/// assert_range!(3.534523, distance)
+	/// assert_range!(3.534523, distance);
+	/// let plane = Plane::xz_plane();
+	/// assert_eq!(4.0, plane.distance_to_point(Vector3::new(1.0, 4.0, 1.0)));
+	/// assert_eq!(-2.5, plane.distance_to_point(Vector3::new(1.0, -2.5, 1.0)));
 	/// ```
 	pub fn distance_to_point(self, point: Vector3) -> f32 { (self.normal * point) + self.distance }
 	
@@ -250,6 +257,26 @@ impl Plane {
 	pub fn is_on_same_side(&self, a: Vector3, b: Vector3) -> bool {
 		self.is_on_positive_side(a) == self.is_on_positive_side(b)
 	}
+	
+	/// Finds where the given ray intersects with the plane
+	/// - **ray**: The ray to intersect the plane with
+	/// 
+	/// **Returns**: Returns the distance along the ray to the point of contact,
+	/// or `None` if the ray is parallel to the plane or the plane is behind the ray's origin
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3, Ray3, Plane};
+	/// let plane = Plane::new(Vector3::up(), -2.0);
+	/// let ray = Ray3::new(Vector3::new(1.0, 5.0, 1.0), Vector3::down());
+	/// let distance = plane.intersect_ray(ray).unwrap();
+	/// assert_eq!(3.0, distance);
+	/// assert_eq!(ray.intersect_plane(Vector3::new(0.0, 2.0, 0.0), Vector3::up()), Some(distance));
+	/// assert_eq!(None, plane.intersect_ray(Ray3::new(Vector3::one(), Vector3::right())));
+	/// ```
+	#[cfg(not(feature = "no_rays"))]
+	pub fn intersect_ray(self, ray: Ray3) -> Option<f32> {
+		ray.intersect_plane(self.closest_point(Vector3::zero()), self.normal)
+	}
 }
 
 #[cfg(not(feature = "no_rays"))]
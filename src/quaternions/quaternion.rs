@@ -117,6 +117,8 @@ impl Quaternion {
 	/// - **euler_angles**: The angles rotating around the relative axis used to create the quaternion
 	/// 
 	/// **Returns**: Returns the new rotation quaternion from the given euler angles (in radians)
+	/// #### Remarks
+	/// The rotations are applied around the z axis first, then the x axis, then the y axis
 	/// #### Examples
 	/// ```
 	/// # use mathx::{Quaternion,Vector3,Math};
@@ -185,6 +187,155 @@ impl Quaternion {
 		));
 	}
 	
+	/// Creates a rotation quaternion that rotates the from direction onto the to direction
+	/// - **from**: The direction to rotate from, does not need to be a unit vector
+	/// - **to**: The direction to rotate towards, does not need to be a unit vector
+	/// 
+	/// **Returns**: Returns the shortest rotation quaternion that rotates the from direction onto the to direction
+	/// #### Remarks
+	/// If either direction is zero or both directions are already aligned, this returns the identity quaternion.
+	/// If the directions are opposite, then this rotates by π around an arbitrary axis perpendicular to the from direction
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3,Quaternion,Math,assert_range};
+	/// let quat = Quaternion::from_to_rotation(Vector3::forward(), Vector3::up());
+	/// let rotated = quat * Vector3::forward();
+	/// assert_range!(0.0, rotated.x(), 0.001);
+	/// assert_range!(1.0, rotated.y(), 0.001);
+	/// assert_range!(0.0, rotated.z(), 0.001);
+	/// assert_range!(Math::PI_OVER_2, quat.angle_between(Quaternion::identity()), 0.001);
+	/// let quat = Quaternion::from_to_rotation(Vector3::forward(), Vector3::back());
+	/// let rotated = quat * Vector3::forward();
+	/// assert_range!(0.0, rotated.x(), 0.001);
+	/// assert_range!(0.0, rotated.y(), 0.001);
+	/// assert_range!(-1.0, rotated.z(), 0.001);
+	/// let quat = Quaternion::from_to_rotation(Vector3::new(0.0, 0.0, 2.0), Vector3::forward());
+	/// assert_eq!(Quaternion::identity(), quat);
+	/// ```
+	#[cfg(not(feature = "no_vectors"))]
+	pub fn from_to_rotation(from: Vector3, to: Vector3) -> Self {
+		if from.square_magnitude() == 0.0 || to.square_magnitude() == 0.0 { return Quaternion::identity(); }
+		
+		let from = from.normalize();
+		let to = to.normalize();
+		let dot = from * to;
+		
+		if dot >= 1.0 - 0.000001 { return Quaternion::identity(); }
+		if dot <= -1.0 + 0.000001 {
+			let axis = if Math::abs(from.x()) < 0.9 { Vector3::right() } else { Vector3::up() };
+			
+			return Quaternion::from_axis_angle(from.cross(axis), Math::PI);
+		}
+		
+		let cross = from.cross(to);
+		
+		return Quaternion::new(1.0 + dot, cross.x(), cross.y(), cross.z()).normalize();
+	}
+	
+	/// Creates a rotation quaternion that looks from the eye position towards the target position
+	/// - **eye**: The position to look from
+	/// - **target**: The position to look towards
+	/// - **up**: The direction that the rotation will try to keep upwards, does not need to be a unit vector
+	/// 
+	/// **Returns**: Returns a rotation quaternion that rotates [`Vector3::forward`] towards the target position
+	/// #### Remarks
+	/// This is the same as using [`Quaternion::look_rotation`] with the direction from the eye to the target,
+	/// so if both positions are the same, this returns the identity quaternion
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3,Quaternion,Math,assert_range};
+	/// let quat = Quaternion::look_at(Vector3::new(0.0, 0.0, 5.0), Vector3::zero(), Vector3::up());
+	/// let forward = quat * Vector3::forward();
+	/// assert_range!(0.0, forward.x(), 0.001);
+	/// assert_range!(0.0, forward.y(), 0.001);
+	/// assert_range!(-1.0, forward.z(), 0.001);
+	/// assert_range!(1.0, (quat * Vector3::up()).y(), 0.001);
+	/// let quat = Quaternion::look_at(Vector3::one(), Vector3::one(), Vector3::up());
+	/// assert_eq!(Quaternion::identity(), quat);
+	/// ```
+	#[cfg(not(feature = "no_vectors"))]
+	pub fn look_at(eye: Vector3, target: Vector3, up: Vector3) -> Self { Quaternion::look_rotation(target - eye, up) }
+	
+	/// Creates a rotation quaternion that looks towards the forward direction while keeping the up direction upwards
+	/// - **forward**: The direction to look towards, does not need to be a unit vector
+	/// - **up**: The direction that the rotation will try to keep upwards, does not need to be a unit vector
+	/// 
+	/// **Returns**: Returns a rotation quaternion that rotates [`Vector3::forward`] towards the given forward direction
+	/// #### Remarks
+	/// If the forward direction is zero, this returns the identity quaternion. If the forward and up directions are
+	/// parallel, then another up direction is picked so the rotation still looks towards the forward direction
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3,Quaternion,Math,assert_range};
+	/// let quat = Quaternion::look_rotation(Vector3::forward(), Vector3::up());
+	/// assert_eq!(Quaternion::identity(), quat);
+	/// let quat = Quaternion::look_rotation(Vector3::right(), Vector3::up());
+	/// let forward = quat * Vector3::forward();
+	/// let up = quat * Vector3::up();
+	/// assert_range!(1.0, forward.x(), 0.001);
+	/// assert_range!(0.0, forward.z(), 0.001);
+	/// assert_range!(1.0, up.y(), 0.001);
+	/// let quat = Quaternion::look_rotation(Vector3::up(), Vector3::up());
+	/// let forward = quat * Vector3::forward();
+	/// assert_range!(1.0, forward.y(), 0.001);
+	/// ```
+	#[cfg(not(feature = "no_vectors"))]
+	pub fn look_rotation(forward: Vector3, up: Vector3) -> Self {
+		if forward.square_magnitude() == 0.0 { return Quaternion::identity(); }
+		
+		let forward = forward.normalize();
+		let mut right = up.cross(forward);
+		
+		if right.square_magnitude() < 0.000001 {
+			let axis = if Math::abs(forward.x()) < 0.9 { Vector3::right() } else { Vector3::up() };
+			right = forward.cross(axis);
+		}
+		
+		let right = right.normalize();
+		let up = forward.cross(right);
+		let trace = right.x() + up.y() + forward.z();
+		
+		if trace > 0.0 {
+			let s = 0.5 / Math::sqrt(trace + 1.0);
+			
+			return Quaternion::new(
+				0.25 / s,
+				(up.z() - forward.y()) * s,
+				(forward.x() - right.z()) * s,
+				(right.y() - up.x()) * s
+			);
+		}
+		else if right.x() > up.y() && right.x() > forward.z() {
+			let s = 2.0 * Math::sqrt(1.0 + right.x() - up.y() - forward.z());
+			
+			return Quaternion::new(
+				(up.z() - forward.y()) / s,
+				0.25 * s,
+				(up.x() + right.y()) / s,
+				(forward.x() + right.z()) / s
+			);
+		}
+		else if up.y() > forward.z() {
+			let s = 2.0 * Math::sqrt(1.0 + up.y() - right.x() - forward.z());
+			
+			return Quaternion::new(
+				(forward.x() - right.z()) / s,
+				(up.x() + right.y()) / s,
+				0.25 * s,
+				(forward.y() + up.z()) / s
+			);
+		}
+		
+		let s = 2.0 * Math::sqrt(1.0 + forward.z() - right.x() - up.y());
+		
+		return Quaternion::new(
+			(right.y() - up.x()) / s,
+			(forward.x() + right.z()) / s,
+			(forward.y() + up.z()) / s,
+			0.25 * s
+		);
+	}
+	
 	// TODO: Add a from_matrix function here
 }
 
@@ -230,52 +381,79 @@ impl Quaternion {
 	/// 
 	/// **Returns**: Returns the euler angles (in radians) in a 3D vector
 	/// #### Remarks
-	/// This isn't very accurate, the x and y coordinates have an error-margin of 0.01
-	/// while the z coordinate has an error-margin of 0.06
+	/// This is the inverse of [`Quaternion::from_euler`], so the angles are found using the same rotation order.
+	/// When the x angle is within about 0.1 degrees of ±90 degrees (gimbal lock) the y and z axes line up, so the whole rotation
+	/// around that axis is given to the y angle while the z angle is set to 0
 	/// #### Examples
 	/// ```
 	/// # use mathx::{Vector3,Quaternion,Math,assert_range};
 	/// let euler = Vector3::new(0.3, 0.2, 1.0);
 	/// let quat = Quaternion::from_euler(euler);
-	/// assert_range!(euler.x(), quat.euler().x(), 0.01);
-	/// assert_range!(euler.y(), quat.euler().y(), 0.01);
-	/// assert_range!(euler.z(), quat.euler().z(), 0.06);
+	/// assert_range!(euler.x(), quat.euler().x(), 0.001);
+	/// assert_range!(euler.y(), quat.euler().y(), 0.001);
+	/// assert_range!(euler.z(), quat.euler().z(), 0.001);
+	/// let euler = Vector3::new(-1.2, 2.5, -0.7);
+	/// let quat = Quaternion::from_euler(euler);
+	/// assert_range!(euler.x(), quat.euler().x(), 0.001);
+	/// assert_range!(euler.y(), quat.euler().y(), 0.001);
+	/// assert_range!(euler.z(), quat.euler().z(), 0.001);
+	/// let euler = Vector3::new(Math::PI_OVER_2, 0.5, 0.0);
+	/// let quat = Quaternion::from_euler(euler);
+	/// assert_range!(euler.x(), quat.euler().x(), 0.001);
+	/// assert_range!(euler.y(), quat.euler().y(), 0.001);
+	/// assert_range!(euler.z(), quat.euler().z(), 0.001);
+	/// let quat = Quaternion::from_euler(Vector3::new(-Math::PI_OVER_2, 0.5, 0.25));
+	/// let vector = Vector3::new(1.0, 2.0, 3.0);
+	/// let expected = quat * vector;
+	/// let actual = Quaternion::from_euler(quat.euler()) * vector;
+	/// assert_range!(-Math::PI_OVER_2, quat.euler().x(), 0.001);
+	/// assert_range!(expected.x(), actual.x(), 0.001);
+	/// assert_range!(expected.y(), actual.y(), 0.001);
+	/// assert_range!(expected.z(), actual.z(), 0.001);
+	/// let euler = Vector3::new(Math::deg2rad(89.0), 0.5, 0.25);
+	/// let quat = Quaternion::from_euler(euler);
+	/// assert_range!(euler.x(), quat.euler().x(), 0.001);
+	/// assert_range!(euler.y(), quat.euler().y(), 0.001);
+	/// assert_range!(euler.z(), quat.euler().z(), 0.001);
+	/// let quat = Quaternion::from_euler(Vector3::new(1.56, 0.5, 0.25));
+	/// let expected = quat * vector;
+	/// let actual = Quaternion::from_euler(quat.euler()) * vector;
+	/// assert_range!(expected.x(), actual.x(), 0.001);
+	/// assert_range!(expected.y(), actual.y(), 0.001);
+	/// assert_range!(expected.z(), actual.z(), 0.001);
 	/// ```
 	#[cfg(not(feature = "no_vectors"))]
 	pub fn euler(&self) -> Vector3 {
-		const SINGULARITY_TEST: f32 = 0.499999995;
+		const SINGULARITY_TEST: f32 = 0.999999;
 		
-		let sq_a = self.a * self.a;
 		let sq_b = self.b * self.b;
 		let sq_c = self.c * self.c;
 		let sq_d = self.d * self.d;
-		let unit = sq_a + sq_b + sq_c + sq_d;
-		let singularity_test = (self.b * self.d) + (self.a * self.c);
+		let unit = self.squared_magnitude();
 		
-		if singularity_test > SINGULARITY_TEST * unit {
-			return Vector3::new(
-				0.0,
-				Math::PI_OVER_2,
-				2.0 * Math::atan2(self.b, self.a)
-			);
-		}
-		else if singularity_test < -SINGULARITY_TEST * unit {
+		if unit == 0.0 { return Vector3::zero(); }
+		
+		let sin_x = 2.0 * ((self.a * self.b) - (self.c * self.d));
+		let sin_y = 2.0 * ((self.a * self.c) + (self.b * self.d));
+		let cos_y = unit - 2.0 * (sq_b + sq_c);
+		
+		if Math::abs(sin_x / unit) > SINGULARITY_TEST {
 			return Vector3::new(
-				0.0,
-				-Math::PI_OVER_2,
-				-2.0 * Math::atan2(self.b, self.a)
+				Math::sign(sin_x) * Math::PI_OVER_2,
+				Math::atan2(
+					2.0 * ((self.a * self.c) - (self.b * self.d)),
+					unit - 2.0 * (sq_c + sq_d)
+				),
+				0.0
 			);
 		}
 		
 		return Vector3::new(
+			Math::atan2(sin_x, Math::hypot(sin_y, cos_y)),
+			Math::atan2(sin_y, cos_y),
 			Math::atan2(
-				2.0 * ((self.a * self.b) - (self.c * self.d)),
-				sq_a - sq_b - sq_c + sq_d
-			),
-			Math::asin(2.0 * singularity_test / unit),
-			Math::atan2(
-				2.0 * ((self.a * self.d) - (self.b * self.c)),
-				sq_a + sq_b - sq_c - sq_d
+				2.0 * ((self.a * self.d) + (self.b * self.c)),
+				unit - 2.0 * (sq_b + sq_d)
 			)
 		);
 	}
@@ -310,16 +488,20 @@ impl Quaternion {
 	/// 
 	/// **Returns**: Returns the euler angles (in degrees) in a 3D vector
 	/// #### Remarks
-	/// This isn't very accurate, the x and y coordinates have an error-margin of 4.0 while
-	/// the z coordinate has an error margin of 14.0
+	/// This is the inverse of [`Quaternion::from_euler_deg`], see [`Quaternion::euler`] for how gimbal lock is handled
 	/// #### Examples
 	/// ```
 	/// # use mathx::{Vector3,Quaternion,Math,assert_range};
 	/// let euler = Vector3::new(-12.0, 40.0, 77.0);
 	/// let quat = Quaternion::from_euler_deg(euler);
-	/// assert_range!(euler.x(), quat.euler_deg().x(), 4.0);
-	/// assert_range!(euler.y(), quat.euler_deg().y(), 4.0);
-	/// assert_range!(euler.z(), quat.euler_deg().z(), 10.0);
+	/// assert_range!(euler.x(), quat.euler_deg().x(), 0.01);
+	/// assert_range!(euler.y(), quat.euler_deg().y(), 0.01);
+	/// assert_range!(euler.z(), quat.euler_deg().z(), 0.01);
+	/// let euler = Vector3::new(90.0, -30.0, 0.0);
+	/// let quat = Quaternion::from_euler_deg(euler);
+	/// assert_range!(euler.x(), quat.euler_deg().x(), 0.01);
+	/// assert_range!(euler.y(), quat.euler_deg().y(), 0.01);
+	/// assert_range!(euler.z(), quat.euler_deg().z(), 0.01);
 	/// ```
 	#[cfg(not(feature = "no_vectors"))]
 	pub fn euler_deg(&self) -> Vector3 {
@@ -406,6 +588,40 @@ impl Quaternion {
 impl Quaternion {
 	// TODO: to_matrix
 	
+	/// Gets the angle between the two rotations
+	/// - **rhs**: The other rotation to get the angle to
+	/// 
+	/// **Returns**: Returns the angle in radians it takes to rotate from this rotation to the other
+	/// #### Remarks
+	/// Both quaternions are expected to be unit quaternions. Since `q` and `-q` represent the same rotation,
+	/// the result is always the shortest angle, between 0 and π
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Quaternion,Vector3,Math,assert_range};
+	/// let quat = Quaternion::from_axis_angle(Vector3::up(), Math::PI_OVER_2);
+	/// assert_range!(Math::PI_OVER_2, Quaternion::identity().angle_between(quat), 0.0001);
+	/// assert_range!(Math::PI_OVER_2, quat.angle_between(Quaternion::identity()), 0.0001);
+	/// assert_range!(Math::PI_OVER_2, (-quat).angle_between(Quaternion::identity()), 0.0001);
+	/// assert_range!(0.0, quat.angle_between(quat), 0.001);
+	/// ```
+	pub fn angle_between(self, rhs: Quaternion) -> f32 {
+		let dot = Math::clamp(Math::abs(self.dot(rhs)), 0.0, 1.0);
+		
+		return 2.0 * Math::acos(dot);
+	}
+	
+	/// Gets the angle between the two rotations in degrees
+	/// - **rhs**: The other rotation to get the angle to
+	/// 
+	/// **Returns**: Returns the angle in degrees it takes to rotate from this rotation to the other
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Quaternion,Vector3,Math,assert_range};
+	/// let quat = Quaternion::from_axis_angle_deg(Vector3::up(), 90.0);
+	/// assert_range!(90.0, Quaternion::identity().angle_between_deg(quat), 0.01);
+	/// ```
+	pub fn angle_between_deg(self, rhs: Quaternion) -> f32 { Math::rad2deg(self.angle_between(rhs)) }
+	
 	/// Conjugates the quaternion, so it turns it from (a + b *i* + c *j* + d *k*) to (a - b *i* - c *j* - d *k*)
 	/// 
 	/// **Returns**: Returns the conjugated quaternion
@@ -453,17 +669,34 @@ impl Quaternion {
 	/// ```
 	pub fn dot(self, rhs: Quaternion) -> f32 { self.a * rhs.a + self.b * rhs.b + self.c * rhs.c + self.d * rhs.d }
 	
-	/// Inverts the quaternion
+	/// Inverts the quaternion, the same as [`Quaternion::invert`]
 	/// 
 	/// **Returns**: Returns the inverted quaternion
 	/// #### Examples
 	/// ```
 	/// # use mathx::Quaternion;
+	/// let quat = Quaternion::new(2.0, -1.0, 0.5, 3.0);
+	/// assert_eq!(Quaternion::identity(), quat * quat.inverse());
+	/// assert_eq!(Quaternion::identity(), quat.inverse() * quat);
+	/// assert_eq!(quat.invert(), quat.inverse());
+	/// ```
+	pub fn inverse(self) -> Self { self.invert() }
+	
+	/// Inverts the quaternion
+	/// 
+	/// **Returns**: Returns the inverted quaternion
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Quaternion,Math,assert_range};
 	/// let actual = Quaternion::new(1.0, -2.0, 3.0, -4.0);
 	/// let expected = Quaternion::new(0.033333333, 0.06666667, -0.1, 0.13333334);
 	/// assert_eq!(expected, actual.invert());
 	/// assert_eq!(Quaternion::identity(), actual * actual.invert());
+	/// assert_eq!(Quaternion::identity(), actual.invert() * actual);
 	/// assert_eq!(Quaternion::identity(), Quaternion::identity().invert());
+	/// let unit = actual.normalize();
+	/// assert_eq!(unit.conjugate(), unit.invert());
+	/// assert_range!(2000.0, Quaternion::new(0.0005, 0.0, 0.0, 0.0).invert().a(), 0.001);
 	/// ```
 	pub fn invert(self) -> Self {
 		let magnitude = self.squared_magnitude();
@@ -478,10 +711,11 @@ impl Quaternion {
 	/// 
 	/// **Returns**: Returns a multiplied quaternion
 	/// #### Remarks
-	/// Multiplying quaternions are not commutative, meaning that `a * b =/= b * a`
+	/// Multiplying quaternions are not commutative, meaning that `a * b =/= b * a`.
+	/// When combining rotations, the rotation on the left is applied last, so `(a * b) * vector` is the same as `a * (b * vector)`
 	/// #### Examples
 	/// ```
-	/// # use mathx::Quaternion;
+	/// # use mathx::{Quaternion,Vector3,Math,assert_range};
 	/// let a = Quaternion::new(1.0, 2.0, 3.0, 4.0);
 	/// let b = Quaternion::new(5.0, 6.0, 7.0, 8.0);
 	/// let expected = Quaternion::new(-60.0, 12.0, 30.0, 24.0);
@@ -489,6 +723,19 @@ impl Quaternion {
 	/// let expected = Quaternion::new(-60.0, 20.0, 14.0, 32.0);
 	/// assert_eq!(expected, b * a);
 	/// assert_eq!(30.0 * Quaternion::identity(), a * a.conjugate());
+	/// let quarter = Quaternion::from_axis_angle(Vector3::up(), Math::PI_OVER_4);
+	/// let expected = Quaternion::from_axis_angle(Vector3::up(), Math::PI_OVER_2);
+	/// let actual = quarter * quarter;
+	/// assert_range!(expected.a(), actual.a(), 0.001);
+	/// assert_range!(expected.c(), actual.c(), 0.001);
+	/// let pitch = Quaternion::from_axis_angle(Vector3::right(), Math::PI_OVER_2);
+	/// let yaw = Quaternion::from_axis_angle(Vector3::up(), Math::PI_OVER_2);
+	/// let vector = Vector3::forward();
+	/// let expected = yaw * (pitch * vector);
+	/// let actual = (yaw * pitch) * vector;
+	/// assert_range!(expected.x(), actual.x(), 0.001);
+	/// assert_range!(expected.y(), actual.y(), 0.001);
+	/// assert_range!(expected.z(), actual.z(), 0.001);
 	/// ```
 	pub fn multiply(self, rhs: Quaternion) -> Self {
 		Quaternion::new(
@@ -528,6 +775,11 @@ impl Quaternion {
 	/// assert_range!(expected.x(), (rotation * vector).x());
 	/// assert_range!(expected.y(), (rotation * vector).y());
 	/// assert_range!(expected.z(), (rotation * vector).z());
+	/// let yaw = Quaternion::from_axis_angle(Vector3::up(), Math::PI_OVER_2);
+	/// let actual = yaw * Vector3::forward();
+	/// assert_range!(1.0, actual.x(), 0.001);
+	/// assert_range!(0.0, actual.y(), 0.001);
+	/// assert_range!(0.0, actual.z(), 0.001);
 	/// ```
 	#[cfg(not(feature = "no_vectors"))]
 	pub fn multiply_vector3(self, rhs: Vector3) -> Vector3 {
@@ -544,24 +796,34 @@ impl Quaternion {
 	
 	/// Normalizes the quaternion
 	/// 
-	/// **Returns**: Returns the normalized quaternion
+	/// **Returns**: Returns the normalized quaternion, or the identity quaternion if the magnitude is approximately 0
 	/// #### Examples
 	/// ```
 	/// # use mathx::Quaternion;
 	/// let actual = Quaternion::new(1.0, 2.0, 3.0, 4.0);
 	/// let expected = Quaternion::new(0.18257418, 0.36514837, 0.5477225, 0.73029673);
 	/// assert_eq!(expected, actual.normalize());
+	/// let actual = Quaternion::new(0.0, 0.0, 0.0, 0.0);
+	/// assert_eq!(Quaternion::identity(), actual.normalize());
 	/// ```
-	pub fn normalize(self) -> Self { self / self.magnitude() }
+	pub fn normalize(self) -> Self {
+		let magnitude = self.magnitude();
+		
+		if Math::approx_zero(magnitude) { return Quaternion::identity(); }
+		
+		return self / magnitude;
+	}
 	
 	/// Spherically interpolates between the two quaternions
 	/// - **rhs**: The other quaternion to interpolate towards
 	/// - **t**: The clamped ratio (t) to interpolate with
 	/// 
 	/// **Returns**: Returns the spherically interpolated quaternion
+	/// #### Remarks
+	/// This always takes the shortest path between the two rotations, and the result is always normalized
 	/// #### Examples
 	/// ```
-	/// # use mathx::{Quaternion,Math,assert_range};
+	/// # use mathx::{Quaternion,Vector3,Math,assert_range};
 	/// let a = Quaternion::new(0.8660254, 0.0, 0.5, 0.0);
 	/// let b = Quaternion::new(0.4158418, 0.1114245, -0.2336062, 0.8718304);
 	/// let expected = Quaternion::new(0.81289685, 0.07065991, 0.1689338, 0.55287176);
@@ -569,6 +831,18 @@ impl Quaternion {
 	/// assert_range!(expected.b(), a.slerp(b, 0.5).b(), 0.001);
 	/// assert_range!(expected.c(), a.slerp(b, 0.5).c(), 0.001);
 	/// assert_range!(expected.d(), a.slerp(b, 0.5).d(), 0.001);
+	/// let a = Quaternion::identity();
+	/// let b = Quaternion::from_axis_angle(Vector3::up(), Math::PI_OVER_2);
+	/// let expected = Quaternion::from_axis_angle(Vector3::up(), Math::PI_OVER_4);
+	/// let actual = a.slerp(b, 0.5);
+	/// assert_range!(expected.a(), actual.a(), 0.001);
+	/// assert_range!(expected.b(), actual.b(), 0.001);
+	/// assert_range!(expected.c(), actual.c(), 0.001);
+	/// assert_range!(expected.d(), actual.d(), 0.001);
+	/// assert_range!(1.0, actual.magnitude(), 0.001);
+	/// let actual = a.slerp(-b, 0.5);
+	/// assert_range!(expected.a(), actual.a(), 0.001);
+	/// assert_range!(expected.c(), actual.c(), 0.001);
 	/// ```
 	pub fn slerp(self, rhs: Quaternion, t: f32) -> Self { self.slerp_unclamped(rhs, t.clamp(0.0, 1.0)) }
 	
@@ -611,6 +885,24 @@ impl Quaternion {
 	
 }
 
+/// Conversions
+impl Quaternion {
+	/// Converts the quaternion into euler angles (in radians), the same as [`Quaternion::euler`]
+	/// 
+	/// **Returns**: Returns the euler angles (in radians) in a 3D vector
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3,Quaternion,Math,assert_range};
+	/// let euler = Vector3::new(0.3, -1.1, 2.0);
+	/// let actual = Quaternion::from_euler(euler).to_euler();
+	/// assert_range!(euler.x(), actual.x(), 0.001);
+	/// assert_range!(euler.y(), actual.y(), 0.001);
+	/// assert_range!(euler.z(), actual.z(), 0.001);
+	/// ```
+	#[cfg(not(feature = "no_vectors"))]
+	pub fn to_euler(self) -> Vector3 { self.euler() }
+}
+
 unsafe impl Send for Quaternion {}
 unsafe impl Sync for Quaternion {}
 
@@ -625,6 +917,54 @@ impl PartialEq for Quaternion {
 	}
 }
 
+/// Compares each component of the quaternions within an absolute epsilon, using the `approx` crate
+/// #### Examples
+/// ```
+/// # #[cfg(feature = "approx")] {
+/// # use mathx::Quaternion;
+/// # use approx::{assert_abs_diff_eq, assert_abs_diff_ne};
+/// assert_abs_diff_eq!(Quaternion::new(1.0, 0.0, 0.0, 0.0), Quaternion::new(1.0000001, 0.0, 0.0, 0.0));
+/// assert_abs_diff_ne!(Quaternion::new(1.0, 0.0, 0.0, 0.0), Quaternion::new(0.9, 0.0, 0.0, 0.0));
+/// assert_abs_diff_eq!(Quaternion::new(1.0, 0.0, 0.0, 0.0), Quaternion::new(0.9, 0.0, 0.0, 0.0), epsilon = 0.2);
+/// # }
+/// ```
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Quaternion {
+	type Epsilon = f32;
+	
+	fn default_epsilon() -> Self::Epsilon { f32::default_epsilon() }
+	
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		f32::abs_diff_eq(&self.a, &other.a, epsilon)
+		&& f32::abs_diff_eq(&self.b, &other.b, epsilon)
+		&& f32::abs_diff_eq(&self.c, &other.c, epsilon)
+		&& f32::abs_diff_eq(&self.d, &other.d, epsilon)
+	}
+}
+
+/// Compares each component of the quaternions with a relative tolerance, using the `approx` crate
+/// #### Examples
+/// ```
+/// # #[cfg(feature = "approx")] {
+/// # use mathx::Quaternion;
+/// # use approx::{assert_relative_eq, assert_relative_ne};
+/// assert_relative_eq!(Quaternion::new(1.0, 0.0, 0.0, 0.0), Quaternion::new(1.0000001, 0.0, 0.0, 0.0));
+/// assert_relative_ne!(Quaternion::new(1.0, 0.0, 0.0, 0.0), Quaternion::new(0.9, 0.0, 0.0, 0.0));
+/// assert_relative_eq!(Quaternion::new(1.0, 0.0, 0.0, 0.0), Quaternion::new(0.9, 0.0, 0.0, 0.0), max_relative = 0.2);
+/// # }
+/// ```
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Quaternion {
+	fn default_max_relative() -> Self::Epsilon { f32::default_max_relative() }
+	
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		f32::relative_eq(&self.a, &other.a, epsilon, max_relative)
+		&& f32::relative_eq(&self.b, &other.b, epsilon, max_relative)
+		&& f32::relative_eq(&self.c, &other.c, epsilon, max_relative)
+		&& f32::relative_eq(&self.d, &other.d, epsilon, max_relative)
+	}
+}
+
 // Display
 #[cfg(not(feature = "no_std"))]
 impl std::fmt::Display for Quaternion {
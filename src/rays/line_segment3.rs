@@ -0,0 +1,217 @@
+
+use crate::{Math, Vector3};
+
+/// A finite 3D line segment that holds a start and end point both as 3D vectors
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy)]
+pub struct LineSegment3 {
+	/// The starting point of the segment
+	start: Vector3,
+	/// The ending point of the segment
+	end: Vector3,
+}
+
+/// Constructors
+impl LineSegment3 {
+	/// Creates a new 3D line segment
+	/// - **start**: The starting point of the segment
+	/// - **end**: The ending point of the segment
+	/// 
+	/// **Returns**: Returns a new 3D line segment
+	/// #### Examples
+	/// ```
+	/// # use mathx::{LineSegment3, Vector3};
+	/// let segment = LineSegment3::new(Vector3::zero(), Vector3::one());
+	/// assert_eq!(Vector3::zero(), segment.start());
+	/// assert_eq!(Vector3::one(), segment.end());
+	/// ```
+	pub fn new(start: Vector3, end: Vector3) -> Self { LineSegment3 { start, end } }
+}
+
+/// Properties
+impl LineSegment3 {
+	/// Gets the starting point of the segment
+	/// 
+	/// **Returns**: Returns the starting point of the segment
+	/// #### Examples
+	/// ```
+	/// # use mathx::{LineSegment3, Vector3};
+	/// let segment = LineSegment3::new(Vector3::zero(), Vector3::one());
+	/// assert_eq!(Vector3::zero(), segment.start());
+	/// ```
+	pub fn start(&self) -> Vector3 { self.start }
+	
+	/// Sets the starting point of the segment
+	/// - **value**: The value to set the starting point to
+	/// #### Examples
+	/// ```
+	/// # use mathx::{LineSegment3, Vector3};
+	/// let mut segment = LineSegment3::new(Vector3::zero(), Vector3::one());
+	/// segment.set_start(Vector3::up());
+	/// assert_eq!(Vector3::up(), segment.start());
+	/// ```
+	pub fn set_start(&mut self, value: Vector3) { self.start = value; }
+	
+	/// Gets the ending point of the segment
+	/// 
+	/// **Returns**: Returns the ending point of the segment
+	/// #### Examples
+	/// ```
+	/// # use mathx::{LineSegment3, Vector3};
+	/// let segment = LineSegment3::new(Vector3::zero(), Vector3::one());
+	/// assert_eq!(Vector3::one(), segment.end());
+	/// ```
+	pub fn end(&self) -> Vector3 { self.end }
+	
+	/// Sets the ending point of the segment
+	/// - **value**: The value to set the ending point to
+	/// #### Examples
+	/// ```
+	/// # use mathx::{LineSegment3, Vector3};
+	/// let mut segment = LineSegment3::new(Vector3::zero(), Vector3::one());
+	/// segment.set_end(Vector3::up());
+	/// assert_eq!(Vector3::up(), segment.end());
+	/// ```
+	pub fn set_end(&mut self, value: Vector3) { self.end = value; }
+	
+	/// Gets the length of the segment
+	/// 
+	/// **Returns**: Returns the length of the segment
+	/// #### Examples
+	/// ```
+	/// # use mathx::{LineSegment3, Vector3};
+	/// let segment = LineSegment3::new(Vector3::zero(), Vector3::new(0.0, 3.0, 4.0));
+	/// assert_eq!(5.0, segment.length());
+	/// ```
+	pub fn length(&self) -> f32 { self.start.distance(self.end) }
+}
+
+/// Public Methods
+impl LineSegment3 {
+	/// Gets the closest point on the segment from the given point
+	/// - **point**: The point to get the closest point from
+	/// 
+	/// **Returns**: Returns the closest point on the segment, clamped between the start and end points
+	/// #### Examples
+	/// ```
+	/// # use mathx::{LineSegment3, Vector3};
+	/// let segment = LineSegment3::new(Vector3::zero(), Vector3::new(4.0, 0.0, 0.0));
+	/// assert_eq!(Vector3::new(2.0, 0.0, 0.0), segment.closest_point(Vector3::new(2.0, 3.0, 0.0)));
+	/// assert_eq!(Vector3::new(4.0, 0.0, 0.0), segment.closest_point(Vector3::new(10.0, 1.0, 0.0)));
+	/// assert_eq!(Vector3::zero(), segment.closest_point(Vector3::new(-3.0, 0.0, 1.0)));
+	/// ```
+	pub fn closest_point(self, point: Vector3) -> Vector3 {
+		let diff = self.end - self.start;
+		let square_length = diff.square_magnitude();
+		
+		if square_length == 0.0 { return self.start; }
+		
+		let t = Math::clamp(((point - self.start) * diff) / square_length, 0.0, 1.0);
+		
+		return self.start + t * diff;
+	}
+	
+	/// Gets the closest pair of points between the two segments
+	/// - **other**: The other segment to find the closest points with
+	/// 
+	/// **Returns**: Returns a tuple of the closest point on this segment and the closest point on the other segment
+	/// #### Examples
+	/// ```
+	/// # use mathx::{LineSegment3, Vector3};
+	/// let a = LineSegment3::new(Vector3::new(-1.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+	/// let b = LineSegment3::new(Vector3::new(0.0, 2.0, -1.0), Vector3::new(0.0, 2.0, 1.0));
+	/// let (p, q) = a.closest_points_between(b);
+	/// assert_eq!(Vector3::zero(), p);
+	/// assert_eq!(Vector3::new(0.0, 2.0, 0.0), q);
+	/// let c = LineSegment3::new(Vector3::new(3.0, 1.0, 0.0), Vector3::new(5.0, 1.0, 0.0));
+	/// let (p, q) = a.closest_points_between(c);
+	/// assert_eq!(Vector3::new(1.0, 0.0, 0.0), p);
+	/// assert_eq!(Vector3::new(3.0, 1.0, 0.0), q);
+	/// ```
+	pub fn closest_points_between(self, other: LineSegment3) -> (Vector3, Vector3) {
+		let d1 = self.end - self.start;
+		let d2 = other.end - other.start;
+		let r = self.start - other.start;
+		let a = d1.square_magnitude();
+		let e = d2.square_magnitude();
+		let f = d2 * r;
+		
+		if a == 0.0 && e == 0.0 { return (self.start, other.start); }
+		
+		let (s, t) = if a == 0.0 {
+			(0.0, Math::clamp(f / e, 0.0, 1.0))
+		}
+		else {
+			let c = d1 * r;
+			
+			if e == 0.0 {
+				(Math::clamp(-c / a, 0.0, 1.0), 0.0)
+			}
+			else {
+				let b = d1 * d2;
+				let denominator = a * e - b * b;
+				let mut s = if denominator != 0.0 {
+					Math::clamp((b * f - c * e) / denominator, 0.0, 1.0)
+				} else { 0.0 };
+				let mut t = (b * s + f) / e;
+				
+				if t < 0.0 {
+					t = 0.0;
+					s = Math::clamp(-c / a, 0.0, 1.0);
+				}
+				else if t > 1.0 {
+					t = 1.0;
+					s = Math::clamp((b - c) / a, 0.0, 1.0);
+				}
+				
+				(s, t)
+			}
+		};
+		
+		return (self.start + s * d1, other.start + t * d2);
+	}
+	
+	/// Gets the distance between the point and the segment
+	/// - **point**: The point to check the distance from
+	/// 
+	/// **Returns**: Returns the distance between the point and the closest point on the segment
+	/// #### Examples
+	/// ```
+	/// # use mathx::{LineSegment3, Vector3};
+	/// let segment = LineSegment3::new(Vector3::zero(), Vector3::new(4.0, 0.0, 0.0));
+	/// assert_eq!(3.0, segment.distance(Vector3::new(2.0, 3.0, 0.0)));
+	/// assert_eq!(5.0, segment.distance(Vector3::new(7.0, 4.0, 0.0)));
+	/// ```
+	pub fn distance(self, point: Vector3) -> f32 { point.distance(self.closest_point(point)) }
+	
+	/// Linearly interpolates from the start to the end of the segment
+	/// - **t**: The ratio value to interpolate between both points, clamped between 0.0 and 1.0
+	/// 
+	/// **Returns**: Returns the interpolated point on the segment
+	/// #### Examples
+	/// ```
+	/// # use mathx::{LineSegment3, Vector3};
+	/// let segment = LineSegment3::new(Vector3::zero(), Vector3::new(4.0, 2.0, 0.0));
+	/// assert_eq!(Vector3::new(1.0, 0.5, 0.0), segment.lerp(0.25));
+	/// assert_eq!(Vector3::new(4.0, 2.0, 0.0), segment.lerp(2.0));
+	/// ```
+	pub fn lerp(self, t: f32) -> Vector3 { self.start.lerp(self.end, t) }
+}
+
+unsafe impl Send for LineSegment3 {}
+unsafe impl Sync for LineSegment3 {}
+
+impl Eq for LineSegment3 {}
+impl PartialEq for LineSegment3 {
+	fn eq(&self, other: &Self) -> bool {
+		self.start == other.start
+		&& self.end == other.end
+	}
+}
+
+#[cfg(not(feature = "no_std"))]
+impl std::fmt::Display for LineSegment3 {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.write_str(&format!("start: {}, end: {}", self.start, self.end))
+	}
+}
@@ -4,3 +4,6 @@ pub use ray2::Ray2;
 
 mod ray3;
 pub use ray3::Ray3;
+
+mod line_segment3;
+pub use line_segment3::LineSegment3;
@@ -2,12 +2,13 @@
 use core::ops::{Neg, Mul, MulAssign, Div, DivAssign};
 
 use crate::Ray2;
-use crate::Vector3;
+use crate::{Math, Vector3};
 use crate::{MulDivScalar, impl_mul, impl_div};
 
 /// A 3D ray that holds an origin and direction both as 3D vectors
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, Copy)]
+#[repr(C)]
 pub struct Ray3 {
 	/// The origin of the ray
 	origin: Vector3,
@@ -132,6 +133,196 @@ impl Ray3 {
 	/// assert_eq!(2.236068, distance);
 	/// ```
 	pub fn distance(self, point: Vector3) -> f32 { point.distance(self.closest_point(point)) }
+	
+	/// Finds where the ray intersects with the given sphere
+	/// - **center**: The center of the sphere
+	/// - **radius**: The radius of the sphere
+	/// 
+	/// **Returns**: Returns the nearest non-negative distance along the ray where it hits the sphere,
+	/// the exit distance if the origin is inside the sphere, or `None` if the ray misses
+	/// #### Remarks
+	/// The distance is measured in multiples of the ray's direction, so `get_point` gives back the point of contact
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Ray3, Vector3, Math, assert_range};
+	/// let ray = Ray3::new(Vector3::zero(), Vector3::forward());
+	/// let hit = ray.intersect_sphere(Vector3::new(0.0, 0.0, 5.0), 1.0);
+	/// assert_range!(4.0, hit.unwrap());
+	/// let tangent = ray.intersect_sphere(Vector3::new(1.0, 0.0, 5.0), 1.0);
+	/// assert_range!(5.0, tangent.unwrap());
+	/// let miss = ray.intersect_sphere(Vector3::new(3.0, 0.0, 5.0), 1.0);
+	/// assert_eq!(None, miss);
+	/// let behind = ray.intersect_sphere(Vector3::new(0.0, 0.0, -5.0), 1.0);
+	/// assert_eq!(None, behind);
+	/// let inside = ray.intersect_sphere(Vector3::new(0.0, 0.0, 0.5), 2.0);
+	/// assert_range!(2.5, inside.unwrap());
+	/// ```
+	pub fn intersect_sphere(self, center: Vector3, radius: f32) -> Option<f32> {
+		let offset = self.origin - center;
+		let a = self.direction.square_magnitude();
+		
+		if a == 0.0 { return None; }
+		
+		let b = offset * self.direction;
+		let c = offset.square_magnitude() - radius * radius;
+		let discriminant = b * b - a * c;
+		
+		if discriminant < 0.0 { return None; }
+		
+		let root = Math::sqrt(discriminant);
+		let near = (-b - root) / a;
+		
+		if near >= 0.0 { return Some(near); }
+		
+		let far = (-b + root) / a;
+		
+		if far >= 0.0 { return Some(far); }
+		
+		return None;
+	}
+	
+	/// Finds where the ray intersects with the given plane
+	/// - **plane_point**: Any point that lies on the plane
+	/// - **plane_normal**: The normal perpendicular to the plane, does not need to be normalized
+	/// 
+	/// **Returns**: Returns the distance along the ray to the point of contact,
+	/// or `None` if the ray is parallel to the plane or the plane is behind the origin
+	/// #### Remarks
+	/// The plane is treated as two-sided, so the normal can face either towards or away from the ray.
+	/// The distance is measured in multiples of the ray's direction, so `get_point` gives back the point of contact
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Ray3, Vector3, Math, assert_range};
+	/// let ray = Ray3::new(Vector3::new(1.0, 5.0, 2.0), Vector3::down());
+	/// let hit = ray.intersect_plane(Vector3::zero(), Vector3::up());
+	/// assert_range!(5.0, hit.unwrap());
+	/// assert_eq!(Vector3::new(1.0, 0.0, 2.0), ray.get_point(hit.unwrap()));
+	/// let ray = Ray3::new(Vector3::new(0.0, 5.0, 0.0), Vector3::right());
+	/// assert_eq!(None, ray.intersect_plane(Vector3::zero(), Vector3::up()));
+	/// let ray = Ray3::new(Vector3::new(0.0, 5.0, 0.0), Vector3::up());
+	/// assert_eq!(None, ray.intersect_plane(Vector3::zero(), Vector3::up()));
+	/// ```
+	pub fn intersect_plane(self, plane_point: Vector3, plane_normal: Vector3) -> Option<f32> {
+		let denominator = plane_normal * self.direction;
+		
+		if Math::approx(denominator, 0.0) { return None; }
+		
+		let distance = (plane_normal * (plane_point - self.origin)) / denominator;
+		
+		if distance < 0.0 { return None; }
+		
+		return Some(distance);
+	}
+	
+	/// Finds where the ray intersects with the given axis-aligned bounding box
+	/// - **min**: The minimum corner of the box
+	/// - **max**: The maximum corner of the box
+	/// 
+	/// **Returns**: Returns the distance along the ray where it enters the box, `0.0` if the origin is inside the box,
+	/// or `None` if the ray misses or the box is behind the origin
+	/// #### Remarks
+	/// The distance is measured in multiples of the ray's direction, so `get_point` gives back the point of contact
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Ray3, Vector3, Math, assert_range};
+	/// let ray = Ray3::new(Vector3::new(0.5, 0.5, -5.0), Vector3::new(0.0, 0.1, 1.0));
+	/// let hit = ray.intersect_aabb(Vector3::zero(), Vector3::one());
+	/// assert_range!(5.0, hit.unwrap());
+	/// let miss = ray.intersect_aabb(Vector3::new(2.0, 0.0, 0.0), Vector3::new(3.0, 1.0, 1.0));
+	/// assert_eq!(None, miss);
+	/// let ray = Ray3::new(Vector3::new(-3.0, 0.5, 0.5), Vector3::right());
+	/// assert_range!(3.0, ray.intersect_aabb(Vector3::zero(), Vector3::one()).unwrap());
+	/// let ray = Ray3::new(Vector3::new(-3.0, 1.5, 0.5), Vector3::right());
+	/// assert_eq!(None, ray.intersect_aabb(Vector3::zero(), Vector3::one()));
+	/// let ray = Ray3::new(Vector3::new(0.5, 0.5, 0.5), Vector3::up());
+	/// assert_eq!(Some(0.0), ray.intersect_aabb(Vector3::zero(), Vector3::one()));
+	/// let ray = Ray3::new(Vector3::new(0.5, 0.5, 3.0), Vector3::forward());
+	/// assert_eq!(None, ray.intersect_aabb(Vector3::zero(), Vector3::one()));
+	/// ```
+	pub fn intersect_aabb(self, min: Vector3, max: Vector3) -> Option<f32> {
+		let origin = [self.origin.x(), self.origin.y(), self.origin.z()];
+		let direction = [self.direction.x(), self.direction.y(), self.direction.z()];
+		let min = [min.x(), min.y(), min.z()];
+		let max = [max.x(), max.y(), max.z()];
+		let mut near = 0.0f32;
+		let mut far = f32::INFINITY;
+		
+		for i in 0..3 {
+			if direction[i] == 0.0 {
+				if origin[i] < min[i] || origin[i] > max[i] { return None; }
+				continue;
+			}
+			
+			let inverse = 1.0 / direction[i];
+			let mut t1 = (min[i] - origin[i]) * inverse;
+			let mut t2 = (max[i] - origin[i]) * inverse;
+			
+			if t1 > t2 { core::mem::swap(&mut t1, &mut t2); }
+			
+			near = near.max(t1);
+			far = far.min(t2);
+			
+			if near > far { return None; }
+		}
+		
+		return Some(near);
+	}
+	
+	/// Reflects the ray off of a surface, creating a new ray that bounces away from the hit point
+	/// - **hit_point**: The point on the surface where the ray hit
+	/// - **normal**: The normal of the surface at the hit point, facing against the ray
+	/// 
+	/// **Returns**: Returns the reflected ray, starting at the hit point and pointing in the reflected direction
+	/// #### Remarks
+	/// The origin of the reflected ray is nudged slightly along the normal so that it does not
+	/// immediately intersect with the same surface again
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Ray3, Vector3, Math, assert_range};
+	/// let ray = Ray3::new(Vector3::new(-1.0, 1.0, 0.0), Vector3::new(1.0, -1.0, 0.0));
+	/// let reflected = ray.reflect(Vector3::zero(), Vector3::up());
+	/// assert_eq!(Vector3::new(1.0, 1.0, 0.0), reflected.direction());
+	/// assert_range!(0.0, reflected.origin().y(), 0.001);
+	/// assert!(reflected.origin().y() > 0.0);
+	/// let ray = Ray3::new(Vector3::new(0.0, 5.0, 0.0), Vector3::down());
+	/// assert_eq!(Vector3::up(), ray.reflect(Vector3::zero(), Vector3::up()).direction());
+	/// ```
+	pub fn reflect(self, hit_point: Vector3, normal: Vector3) -> Ray3 {
+		let normal = normal.normalize();
+		let epsilon = 0.0001;
+		
+		return Ray3::new(hit_point + epsilon * normal, self.direction.reflect(normal));
+	}
+	
+	/// Samples evenly spaced points along the ray
+	/// - **start**: The distance along the ray to start sampling from
+	/// - **end**: The distance along the ray to stop sampling at
+	/// - **count**: The number of points to sample
+	/// 
+	/// **Returns**: Returns an iterator over `count` points from `start` to `end`, including both ends
+	/// #### Remarks
+	/// A `count` of 0 yields no points and a `count` of 1 yields only the point at `start`
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Ray3, Vector3};
+	/// let ray = Ray3::new(Vector3::zero(), Vector3::right());
+	/// let mut points = ray.sample(0.0, 2.0, 5);
+	/// assert_eq!(Some(Vector3::new(0.0, 0.0, 0.0)), points.next());
+	/// assert_eq!(Some(Vector3::new(0.5, 0.0, 0.0)), points.next());
+	/// assert_eq!(Some(Vector3::new(1.0, 0.0, 0.0)), points.next());
+	/// assert_eq!(Some(Vector3::new(1.5, 0.0, 0.0)), points.next());
+	/// assert_eq!(Some(Vector3::new(2.0, 0.0, 0.0)), points.next());
+	/// assert_eq!(None, points.next());
+	/// assert_eq!(0, ray.sample(0.0, 2.0, 0).count());
+	/// let mut points = ray.sample(1.0, 2.0, 1);
+	/// assert_eq!(Some(Vector3::right()), points.next());
+	/// assert_eq!(None, points.next());
+	/// ```
+	pub fn sample(self, start: f32, end: f32, count: usize) -> impl Iterator<Item = Vector3> {
+		let step = if count > 1 { 1.0 / (count - 1) as f32 } else { 0.0 };
+		
+		return (0..count).map(move |i| self.get_point(Math::lerp_unclamped(start, end, step * i as f32)));
+	}
 }
 
 impl From<Ray2> for Ray3 {
@@ -140,9 +331,23 @@ impl From<Ray2> for Ray3 {
 	}
 }
 
+/// Creates a 3D ray at the origin that's pointing forward
+/// #### Examples
+/// ```
+/// # use mathx::{Ray3,Vector3};
+/// let ray = Ray3::default();
+/// assert_eq!(Vector3::zero(), ray.origin());
+/// assert_eq!(Vector3::forward(), ray.direction());
+/// ```
+impl Default for Ray3 {
+	fn default() -> Self { Ray3::new(Vector3::zero(), Vector3::forward()) }
+}
+
 unsafe impl Send for Ray3 {}
 unsafe impl Sync for Ray3 {}
 
+const _: () = assert!(core::mem::size_of::<Ray3>() == 24 && core::mem::align_of::<Ray3>() == 4);
+
 impl Eq for Ray3 {}
 impl PartialEq for Ray3 {
 	fn eq(&self, other: &Self) -> bool {
@@ -7,7 +7,9 @@ use crate::{AddSubArithmetic, MulDivScalar, use_impl_ops, impl_add, impl_sub, im
 
 /// A 2D vector that holds an x-coordinate and y-coordinate
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 #[derive(Debug, Clone, Copy)]
+#[repr(C)]
 pub struct Vector2 {
 	/// The x coordinate of the vector
 	x: f32,
@@ -22,14 +24,19 @@ impl Vector2 {
 	/// - **y**: The y coordinate of the vector
 	/// 
 	/// **Returns**: Returns a new 2D vector
+	/// #### Remarks
+	/// This is a `const fn`, so it can be used to declare constants
 	/// #### Examples
 	/// ```
 	/// # use mathx::Vector2;
 	/// let vector = Vector2::new(1.2, 3.45);
 	/// assert_eq!(1.2, vector.x());
 	/// assert_eq!(3.45, vector.y());
+	/// const OFFSET: Vector2 = Vector2::new(4.0, -2.0);
+	/// const OFFSET_X: f32 = OFFSET.x();
+	/// assert_eq!(4.0, OFFSET_X);
 	/// ```
-	pub fn new(x: f32, y: f32) -> Self { Vector2 { x, y } }
+	pub const fn new(x: f32, y: f32) -> Self { Vector2 { x, y } }
 	
 	/// Creates a new 2D vector from a 3D vector
 	/// - **vector**: The 3D vector to convert from
@@ -55,7 +62,7 @@ impl Vector2 {
 	/// assert_eq!(0.0, vector.x());
 	/// assert_eq!(0.0, vector.y());
 	/// ```
-	pub fn zero() -> Self { Vector2 { x: 0.0, y: 0.0 } }
+	pub const fn zero() -> Self { Vector2 { x: 0.0, y: 0.0 } }
 	
 	/// Creates a 2D unit vector that's pointing to the left: (-1, 0)
 	/// 
@@ -67,7 +74,7 @@ impl Vector2 {
 	/// assert_eq!(-1.0, vector.x());
 	/// assert_eq!(0.0, vector.y());
 	/// ```
-	pub fn left() -> Self { Vector2 { x: -1.0, y: 0.0 } }
+	pub const fn left() -> Self { Vector2 { x: -1.0, y: 0.0 } }
 	
 	/// Creates a 2D unit vector that's pointing to the right: (1, 0)
 	/// 
@@ -79,7 +86,7 @@ impl Vector2 {
 	/// assert_eq!(1.0, vector.x());
 	/// assert_eq!(0.0, vector.y());
 	/// ```
-	pub fn right() -> Self { Vector2 { x: 1.0, y: 0.0 } }
+	pub const fn right() -> Self { Vector2 { x: 1.0, y: 0.0 } }
 	
 	/// Creates a 2D unit vector that's pointing up: (0, 1)
 	/// 
@@ -91,7 +98,7 @@ impl Vector2 {
 	/// assert_eq!(0.0, vector.x());
 	/// assert_eq!(1.0, vector.y());
 	/// ```
-	pub fn up() -> Self { Vector2 { x: 0.0, y: 1.0 } }
+	pub const fn up() -> Self { Vector2 { x: 0.0, y: 1.0 } }
 	
 	/// Creates a 2D unit vector that's pointing down: (0, -1)
 	/// 
@@ -103,7 +110,7 @@ impl Vector2 {
 	/// assert_eq!(0.0, vector.x());
 	/// assert_eq!(-1.0, vector.y());
 	/// ```
-	pub fn down() -> Self { Vector2 { x: 0.0, y: -1.0 } }
+	pub const fn down() -> Self { Vector2 { x: 0.0, y: -1.0 } }
 	
 	/// Creates a 2D vector that contains 1 in all it's components: (1, 1)
 	/// 
@@ -115,7 +122,7 @@ impl Vector2 {
 	/// assert_eq!(1.0, vector.x());
 	/// assert_eq!(1.0, vector.y());
 	/// ```
-	pub fn one() -> Self { Vector2 { x: 1.0, y: 1.0 } }
+	pub const fn one() -> Self { Vector2 { x: 1.0, y: 1.0 } }
 	
 	/// Creates a 2D vector from a single angle (heading)
 	/// - **angle**: The angle in radians to create the 2D vector from
@@ -156,6 +163,64 @@ impl Vector2 {
 		
 		Vector2::new(cos, sin)
 	}
+	
+	/// Creates a 2D vector from polar coordinates
+	/// - **angle**: The angle in radians to create the 2D vector from
+	/// - **length**: The length of the 2D vector
+	/// 
+	/// **Returns**: Returns a 2D vector pointing towards the angle with the given length
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector2,Math,assert_range};
+	/// let vector = Vector2::from_polar(0.0, 5.0);
+	/// assert_range!(5.0, vector.x());
+	/// assert_range!(0.0, vector.y());
+	/// let vector = Vector2::from_polar(Math::PI_OVER_2, 5.0);
+	/// assert_range!(0.0, vector.x());
+	/// assert_range!(5.0, vector.y());
+	/// ```
+	pub fn from_polar(angle: f32, length: f32) -> Self { length * Vector2::from_heading(angle) }
+	
+	/// Creates a 2D vector from polar coordinates
+	/// - **angle**: The angle in degrees to create the 2D vector from
+	/// - **length**: The length of the 2D vector
+	/// 
+	/// **Returns**: Returns a 2D vector pointing towards the angle with the given length
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector2,Math,assert_range};
+	/// let vector = Vector2::from_polar_deg(0.0, 5.0);
+	/// assert_range!(5.0, vector.x());
+	/// assert_range!(0.0, vector.y());
+	/// let vector = Vector2::from_polar_deg(90.0, 5.0);
+	/// assert_range!(0.0, vector.x());
+	/// assert_range!(5.0, vector.y());
+	/// ```
+	pub fn from_polar_deg(angle: f32, length: f32) -> Self { length * Vector2::from_heading_deg(angle) }
+	
+	/// Creates a random 2D vector of unit length, uniformly distributed around the circle
+	/// - **rng**: The random number generator to sample with
+	/// 
+	/// **Returns**: Returns a random 2D vector of unit length
+	/// #### Examples
+	/// ```
+	/// # #[cfg(feature = "rand")] {
+	/// # use mathx::{Vector2,Math,assert_range};
+	/// # use rand::SeedableRng;
+	/// let mut rng = rand::rngs::StdRng::seed_from_u64(1234);
+	/// let mut sum = Vector2::zero();
+	/// for _ in 0..10000 {
+	/// 	let vector = Vector2::random_unit(&mut rng);
+	/// 	assert_range!(1.0, vector.magnitude(), 0.0001);
+	/// 	sum += vector;
+	/// }
+	/// assert_range!(0.0, (sum / 10000.0).magnitude(), 0.05);
+	/// # }
+	/// ```
+	#[cfg(feature = "rand")]
+	pub fn random_unit<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+		Vector2::from_heading(rng.gen_range(0.0..Math::TWO_PI))
+	}
 }
 
 /// Properties
@@ -163,7 +228,7 @@ impl Vector2 {
 	/// Gets the x coordinate of the vector
 	/// 
 	/// **Returns**: Returns the x coordinate of the vector
-	pub fn x(&self) -> f32 { self.x }
+	pub const fn x(&self) -> f32 { self.x }
 	
 	/// Sets the x coordinate of the vector
 	/// - **value**: The value to set the x coordinate of the vector
@@ -172,12 +237,39 @@ impl Vector2 {
 	/// Gets the y coordinate of the vector
 	/// 
 	/// **Returns**: Returns the y coordinate of the vector
-	pub fn y(&self) -> f32 { self.y }
+	pub const fn y(&self) -> f32 { self.y }
 	
 	/// Sets the y coordinate of the vector
 	/// - **value**: The value to set the y coordinate of the vector
 	pub fn set_y(&mut self, value: f32) { self.y = value; }
 	
+	/// Sets all the coordinates of the vector at once
+	/// - **x**: The value to set the x coordinate of the vector
+	/// - **y**: The value to set the y coordinate of the vector
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// let mut vector = Vector2::one();
+	/// vector.set(3.0, -4.0);
+	/// assert_eq!(3.0, vector.x());
+	/// assert_eq!(-4.0, vector.y());
+	/// ```
+	pub fn set(&mut self, x: f32, y: f32) {
+		self.x = x;
+		self.y = y;
+	}
+	
+	/// Sets all the coordinates of the vector at once from an array
+	/// - **values**: The array of values to set the x and y coordinates (respectively) of the vector
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// let mut vector = Vector2::one();
+	/// vector.set_array([3.0, -4.0]);
+	/// assert_eq!(Vector2::new(3.0, -4.0), vector);
+	/// ```
+	pub fn set_array(&mut self, values: [f32; 2]) { self.set(values[0], values[1]); }
+	
 	/// Get the heading from the vector in radians
 	/// 
 	/// **Returns**: Returns the heading from the vector in radians
@@ -249,6 +341,20 @@ impl Vector2 {
 		return Math::sqrt(magnitude);
 	}
 	
+	/// Sets the magnitude of the vector while keeping its direction
+	/// - **magnitude**: The magnitude to set the vector to
+	/// #### Remarks
+	/// A zero vector has no direction to keep, so it will stay as zero
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// let mut vector = Vector2::new(3.0, 4.0);
+	/// vector.set_magnitude(10.0);
+	/// assert_eq!(Vector2::new(6.0, 8.0), vector);
+	/// assert_eq!(10.0, vector.magnitude());
+	/// ```
+	pub fn set_magnitude(&mut self, magnitude: f32) { *self = self.with_magnitude(magnitude); }
+	
 	/// Gets the magnitude squared, avoiding the use of a square root
 	/// 
 	/// **Returns**: Returns the magnitude of the vector squared
@@ -263,6 +369,22 @@ impl Vector2 {
 
 /// Public Methods
 impl Vector2 {
+	/// Gets the absolute value of each component of the vector
+	/// 
+	/// **Returns**: Returns the vector with the absolute value applied to each component
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// let vector = Vector2::new(-1.5, 2.9).abs();
+	/// assert_eq!(Vector2::new(1.5, 2.9), vector);
+	/// ```
+	pub fn abs(self) -> Self {
+		Vector2::new(
+			Math::abs(self.x),
+			Math::abs(self.y)
+		)
+	}
+	
 	/// Gets the angle between the two vectors in radians
 	/// - **rhs**: The other vector to get the angle from
 	/// 
@@ -291,9 +413,81 @@ impl Vector2 {
 	/// let a = Vector2::new(0.25, -0.5);
 	/// let b = Vector2::new(2.0, 0.5);
 	/// assert_range!(77.4712, a.angle_between_deg(b), 0.01);
+	/// assert_range!(90.0, Vector2::right().angle_between_deg(Vector2::up()), 0.001);
 	/// ```
 	pub fn angle_between_deg(self, rhs: Vector2) -> f32 { return Math::rad2deg(self.angle_between(rhs)); }
 	
+	/// Bounces this vector off of a surface, reflecting it and scaling it down by the restitution
+	/// - **normal**: The normal vector of the surface to bounce off of
+	/// - **restitution**: How much of the vector's magnitude is kept after the bounce, clamped between 0.0 and 1.0
+	/// 
+	/// **Returns**: Returns the bounced vector
+	/// #### Remarks
+	/// A restitution of 1.0 is a perfect bounce that keeps the full magnitude, while 0.0 stops the vector entirely
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// let velocity = Vector2::new(3.0, -4.0);
+	/// assert_eq!(Vector2::new(3.0, 4.0), velocity.bounce(Vector2::up(), 1.0));
+	/// assert_eq!(Vector2::new(1.5, 2.0), velocity.bounce(Vector2::up(), 0.5));
+	/// assert_eq!(Vector2::zero(), velocity.bounce(Vector2::up(), 0.0));
+	/// assert_eq!(Vector2::new(3.0, 4.0), velocity.bounce(Vector2::up(), 2.0));
+	/// ```
+	pub fn bounce(self, normal: Vector2, restitution: f32) -> Self { Math::clamp(restitution, 0.0, 1.0) * self.reflect(normal) }
+	
+	/// Rounds up each component of the vector
+	/// 
+	/// **Returns**: Returns the vector with each component rounded up
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// let vector = Vector2::new(-1.5, 2.9).ceil();
+	/// assert_eq!(Vector2::new(-1.0, 3.0), vector);
+	/// ```
+	pub fn ceil(self) -> Self {
+		Vector2::new(
+			Math::ceil(self.x),
+			Math::ceil(self.y)
+		)
+	}
+	
+	/// Gets the Chebyshev distance (the largest difference of any component) between the two vectors
+	/// - **rhs**: The other vector to get the distance between
+	/// 
+	/// **Returns**: Returns the Chebyshev distance between the two vectors
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// let distance = Vector2::zero().chebyshev_distance(Vector2::new(3.0, 4.0));
+	/// assert_eq!(4.0, distance);
+	/// let distance = Vector2::new(-1.0, 2.0).chebyshev_distance(Vector2::new(3.0, 1.0));
+	/// assert_eq!(4.0, distance);
+	/// ```
+	pub fn chebyshev_distance(self, rhs: Vector2) -> f32 {
+		Math::max(
+			Math::abs(rhs.x - self.x),
+			Math::abs(rhs.y - self.y)
+		)
+	}
+	
+	/// Compares the lengths of the two vectors, useful for sorting vectors by their length
+	/// - **other**: The other vector to compare with
+	/// 
+	/// **Returns**: Returns the ordering of this vector's length compared to the other vector's length,
+	/// or `None` if either vector contains `NaN`
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// let mut vectors = [Vector2::new(3.0, 4.0), Vector2::new(1.0, 0.0), Vector2::new(0.0, -2.0)];
+	/// vectors.sort_by(|a, b| a.cmp_magnitude(b).unwrap());
+	/// assert_eq!([Vector2::new(1.0, 0.0), Vector2::new(0.0, -2.0), Vector2::new(3.0, 4.0)], vectors);
+	/// let longest = vectors.iter().max_by(|a, b| a.cmp_magnitude(b).unwrap());
+	/// assert_eq!(Some(&Vector2::new(3.0, 4.0)), longest);
+	/// ```
+	pub fn cmp_magnitude(&self, other: &Self) -> Option<core::cmp::Ordering> {
+		self.square_magnitude().partial_cmp(&other.square_magnitude())
+	}
+	
 	/// Gets the distance between the two vectors
 	/// - **rhs**: The other vector to get the distance between
 	/// 
@@ -346,6 +540,65 @@ impl Vector2 {
 		self.x * rhs.x + self.y * rhs.y
 	}
 	
+	/// Rounds down each component of the vector
+	/// 
+	/// **Returns**: Returns the vector with each component rounded down
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// let vector = Vector2::new(-1.5, 2.9).floor();
+	/// assert_eq!(Vector2::new(-2.0, 2.0), vector);
+	/// ```
+	pub fn floor(self) -> Self {
+		Vector2::new(
+			Math::floor(self.x),
+			Math::floor(self.y)
+		)
+	}
+	
+	/// Gets the exact bit patterns of each component, useful as a key for hashing and deduplicating
+	/// 
+	/// **Returns**: Returns the bit patterns of each component
+	/// #### Remarks
+	/// The key compares bitwise rather than approximately, so `-0.0` and `0.0` make different keys
+	/// and two 2D vectors that are approximately equal may still have different keys.
+	/// Vector2 does not implement `Hash`, since its approximate equality would break the rule that equal values hash the same,
+	/// so use this key when hashing is needed
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// use std::collections::HashMap;
+	/// let mut names = HashMap::new();
+	/// names.insert(Vector2::new(1.0, -2.5).hash_key(), "first");
+	/// assert_eq!(Some(&"first"), names.get(&Vector2::new(1.0, -2.5).hash_key()));
+	/// assert_ne!(Vector2::new(0.0, 0.0).hash_key(), Vector2::new(-0.0, 0.0).hash_key());
+	/// ```
+	pub fn hash_key(&self) -> [u32; 2] { [self.x.to_bits(), self.y.to_bits()] }
+	
+	/// Finds if the vector is approximately a unit vector (magnitude of 1)
+	/// 
+	/// **Returns**: Returns true if the magnitude of the vector is approximately 1
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// assert!(Vector2::new(3.0, 4.0).normalize().is_normalized());
+	/// assert!(!Vector2::zero().is_normalized());
+	/// assert!(!Vector2::new(0.0, 2.0).is_normalized());
+	/// ```
+	pub fn is_normalized(self) -> bool { Math::approx(self.square_magnitude(), 1.0) }
+	
+	/// Finds if the vector is approximately a zero vector
+	/// 
+	/// **Returns**: Returns true if all the components of the vector are approximately 0
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// assert!(Vector2::zero().is_zero());
+	/// assert!(!Vector2::new(3.0, 4.0).normalize().is_zero());
+	/// assert!(!Vector2::new(0.0, 2.0).is_zero());
+	/// ```
+	pub fn is_zero(self) -> bool { Math::approx(self.square_magnitude(), 0.0) }
+	
 	/// Linearly interpolates between the this and the other vector
 	/// - **rhs**: The other vector to end from
 	/// - **t**: The ratio value to interpolate between both vectors. Clamped between 0.0 and 1.0
@@ -381,6 +634,22 @@ impl Vector2 {
 		)
 	}
 	
+	/// Gets the Manhattan distance (the sum of the differences of each component) between the two vectors
+	/// - **rhs**: The other vector to get the distance between
+	/// 
+	/// **Returns**: Returns the Manhattan distance between the two vectors
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// let distance = Vector2::zero().manhattan_distance(Vector2::new(3.0, 4.0));
+	/// assert_eq!(7.0, distance);
+	/// let distance = Vector2::new(-1.0, 2.0).manhattan_distance(Vector2::new(3.0, 1.0));
+	/// assert_eq!(5.0, distance);
+	/// ```
+	pub fn manhattan_distance(self, rhs: Vector2) -> f32 {
+		Math::abs(rhs.x - self.x) + Math::abs(rhs.y - self.y)
+	}
+	
 	/// Moves this vector towards the target vector, it will never move past the target
 	/// - **target**: The target vector to move towards
 	/// - **delta**: The delta distance to try and move with, defines the maximum distance moved
@@ -407,9 +676,26 @@ impl Vector2 {
 		return diff * dir + self;
 	}
 	
+	/// Linearly interpolates between the this and the other vector, then normalizes the result
+	/// - **rhs**: The other vector to end from
+	/// - **t**: The ratio value to interpolate between both vectors. Clamped between 0.0 and 1.0
+	/// 
+	/// **Returns**: Returns the normalized interpolated vector
+	/// #### Remarks
+	/// This is a cheaper alternative to spherically interpolating the vectors as it avoids any trigonometry,
+	/// but the angle does not change at a constant speed as `t` moves from 0.0 to 1.0
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector2,Math,assert_range};
+	/// let vector = Vector2::right().nlerp(Vector2::up(), 0.5);
+	/// assert_range!(1.0, vector.magnitude());
+	/// assert_eq!(Vector2::new(0.7071068, 0.7071068), vector);
+	/// ```
+	pub fn nlerp(self, rhs: Vector2, t: f32) -> Self { self.lerp(rhs, t).normalize() }
+	
 	/// Normalizes the vector
 	/// 
-	/// **Returns**: Returns the unit vector version of this vector
+	/// **Returns**: Returns the unit vector version of this vector, or a zero vector if the magnitude is approximately zero
 	/// #### Examples
 	/// ```
 	/// # use mathx::{Vector2,Math,assert_range};
@@ -419,10 +705,23 @@ impl Vector2 {
 	/// let vector = Vector2::new(-0.1, 1.0).normalize();
 	/// assert_range!(-0.09950372, vector.x());
 	/// assert_range!(0.99503714, vector.y());
+	/// assert_eq!(Vector2::zero(), Vector2::new(1e-7, 0.0).normalize());
 	/// ```
-	pub fn normalize(self) -> Self { self / self.magnitude() }
+	pub fn normalize(self) -> Self { self.normalize_or(Vector2::zero()) }
 	
-	/// Creates a perpendicular 2D vector
+	/// Normalizes the vector, using the fallback when the vector has no length to normalize
+	/// - **fallback**: The vector to return when this vector is zero
+	/// 
+	/// **Returns**: Returns the unit vector version of this vector, or the fallback if this vector is zero
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// assert_eq!(Vector2::new(0.6, 0.8), Vector2::new(3.0, 4.0).normalize_or(Vector2::up()));
+	/// assert_eq!(Vector2::up(), Vector2::zero().normalize_or(Vector2::up()));
+	/// ```
+	pub fn normalize_or(self, fallback: Vector2) -> Self { self.try_normalize().unwrap_or(fallback) }
+	
+	/// Creates a perpendicular 2D vector, rotated clockwise: (y, -x)
 	/// 
 	/// **Returns**: Returns a perpendicular 2D vector
 	/// #### Examples
@@ -431,9 +730,24 @@ impl Vector2 {
 	/// let vector = Vector2::new(1.0, 2.0);
 	/// let perpendicular = vector.perpendicular();
 	/// assert_eq!(0.0, vector * perpendicular);
+	/// assert_eq!(Vector2::down(), Vector2::right().perpendicular());
 	/// ```
 	pub fn perpendicular(self) -> Self { Vector2::new(self.y, -self.x) }
 	
+	/// Creates a perpendicular 2D vector, rotated counter-clockwise: (-y, x)
+	/// 
+	/// **Returns**: Returns a perpendicular 2D vector
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// let vector = Vector2::new(1.0, 2.0);
+	/// let perpendicular = vector.perpendicular_ccw();
+	/// assert_eq!(0.0, vector * perpendicular);
+	/// assert_eq!(Vector2::up(), Vector2::right().perpendicular_ccw());
+	/// assert_eq!(-vector.perpendicular(), perpendicular);
+	/// ```
+	pub fn perpendicular_ccw(self) -> Self { Vector2::new(-self.y, self.x) }
+	
 	/// Projects this vector onto the given vector
 	/// - **rhs**: The vector to project onto
 	/// 
@@ -466,6 +780,7 @@ impl Vector2 {
 	/// let expected = Vector2::new(-0.32, 0.24);
 	/// assert_range!(expected.x(), a.reject(b).x());
 	/// assert_range!(expected.y(), a.reject(b).y());
+	/// assert_eq!(a, a.project(b) + a.reject(b));
 	/// ```
 	pub fn reject(self, rhs: Vector2) -> Self {
 		self - self.project(rhs)
@@ -493,6 +808,111 @@ impl Vector2 {
 		return dot * normal + self;
 	}
 	
+	/// Rotates the vector counter-clockwise by the given angle in radians
+	/// - **angle**: The angle to rotate the vector by in radians
+	/// 
+	/// **Returns**: Returns the rotated vector, keeping the same magnitude
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector2,Math,assert_range};
+	/// let vector = Vector2::right().rotate(Math::PI_OVER_2);
+	/// assert_range!(0.0, vector.x());
+	/// assert_range!(1.0, vector.y());
+	/// let vector = Vector2::right().rotate(Math::PI);
+	/// assert_range!(-1.0, vector.x());
+	/// assert_range!(0.0, vector.y());
+	/// let vector = Vector2::new(3.0, 4.0).rotate(1.0);
+	/// assert_range!(5.0, vector.magnitude());
+	/// ```
+	pub fn rotate(self, angle: f32) -> Self {
+		let (sin, cos) = Math::sin_cos(angle);
+		
+		Vector2::new(
+			self.x * cos - self.y * sin,
+			self.x * sin + self.y * cos
+		)
+	}
+	
+	/// Rotates the vector counter-clockwise by the given angle in degrees
+	/// - **angle**: The angle to rotate the vector by in degrees
+	/// 
+	/// **Returns**: Returns the rotated vector, keeping the same magnitude
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector2,Math,assert_range};
+	/// let vector = Vector2::right().rotate_deg(90.0);
+	/// assert_range!(0.0, vector.x());
+	/// assert_range!(1.0, vector.y());
+	/// assert_eq!(Vector2::right().rotate(Math::PI_OVER_2), vector);
+	/// let vector = Vector2::right().rotate_deg(180.0);
+	/// assert_range!(-1.0, vector.x());
+	/// assert_range!(0.0, vector.y());
+	/// ```
+	pub fn rotate_deg(self, angle: f32) -> Self { self.rotate(Math::deg2rad(angle)) }
+	
+	/// Rotates the vector towards the target vector, turning by at most the given angle in radians
+	/// - **target**: The target vector to rotate towards
+	/// - **max_radians**: The maximum angle the vector will rotate by in radians
+	/// 
+	/// **Returns**: Returns the rotated vector, keeping the same magnitude
+	/// #### Remarks
+	/// If the target is within the maximum angle, this snaps to the direction of the target.
+	/// If either vector is zero, this vector is returned as it is
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector2,Math,assert_range};
+	/// let vector = Vector2::right().rotate_towards(Vector2::up(), 0.1);
+	/// assert_range!(0.1, Vector2::right().angle_between(vector));
+	/// assert!(vector.angle_between(Vector2::up()) > 0.0);
+	/// assert_eq!(Vector2::up(), Vector2::right().rotate_towards(Vector2::up(), Math::PI));
+	/// let vector = Vector2::new(2.0, 0.0).rotate_towards(Vector2::new(0.0, -5.0), Math::PI);
+	/// assert_eq!(Vector2::new(0.0, -2.0), vector);
+	/// let vector = Vector2::right().rotate_towards(Vector2::down(), 0.1);
+	/// assert_range!(-0.1, Vector2::right().signed_angle_between(vector));
+	/// ```
+	pub fn rotate_towards(self, target: Vector2, max_radians: f32) -> Self {
+		if self.square_magnitude() == 0.0 || target.square_magnitude() == 0.0 { return self; }
+		
+		let angle = self.signed_angle_between(target);
+		let max_radians = Math::max(max_radians, 0.0);
+		
+		if Math::abs(angle) <= max_radians {
+			return self.magnitude() * target.normalize();
+		}
+		
+		return self.rotate(if angle < 0.0 { -max_radians } else { max_radians });
+	}
+	
+	/// Rotates the vector towards the target vector, turning by at most the given angle in degrees
+	/// - **target**: The target vector to rotate towards
+	/// - **max_degrees**: The maximum angle the vector will rotate by in degrees
+	/// 
+	/// **Returns**: Returns the rotated vector, keeping the same magnitude
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector2,Math,assert_range};
+	/// let vector = Vector2::right().rotate_towards_deg(Vector2::up(), 10.0);
+	/// assert_range!(10.0, Vector2::right().angle_between_deg(vector), 0.001);
+	/// assert_eq!(Vector2::up(), Vector2::right().rotate_towards_deg(Vector2::up(), 180.0));
+	/// ```
+	pub fn rotate_towards_deg(self, target: Vector2, max_degrees: f32) -> Self { self.rotate_towards(target, Math::deg2rad(max_degrees)) }
+	
+	/// Rounds each component of the vector to the nearest whole number
+	/// 
+	/// **Returns**: Returns the vector with each component rounded to the nearest whole number
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// let vector = Vector2::new(-1.5, 2.9).round();
+	/// assert_eq!(Vector2::new(-2.0, 3.0), vector);
+	/// ```
+	pub fn round(self) -> Self {
+		Vector2::new(
+			Math::round(self.x),
+			Math::round(self.y)
+		)
+	}
+	
 	/// Scales the vector using another vector, multiplying everything component-wise
 	/// - **rhs**: The other vector to scale with
 	/// 
@@ -540,9 +960,43 @@ impl Vector2 {
 	/// let a = Vector2::new(0.25, -0.5);
 	/// let b = Vector2::new(-2.0, 0.5);
 	/// assert_range!(-130.6013, a.signed_angle_between_deg(b), 0.01);
+	/// assert_range!(90.0, Vector2::right().signed_angle_between_deg(Vector2::up()), 0.001);
+	/// assert_range!(-90.0, Vector2::up().signed_angle_between_deg(Vector2::right()), 0.001);
 	/// ```
 	pub fn signed_angle_between_deg(self, rhs: Vector2) -> f32 { Math::rad2deg(self.signed_angle_between(rhs)) }
 	
+	/// Normalizes the vector, if it has any length to normalize
+	/// 
+	/// **Returns**: Returns the unit vector version of this vector, or `None` if this vector is approximately zero
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// assert_eq!(Some(Vector2::new(0.6, 0.8)), Vector2::new(3.0, 4.0).try_normalize());
+	/// assert_eq!(None, Vector2::zero().try_normalize());
+	/// assert_eq!(None, Vector2::new(0.0000001, 0.0).try_normalize());
+	/// ```
+	pub fn try_normalize(self) -> Option<Self> {
+		let magnitude = self.magnitude();
+		
+		if Math::approx_zero(magnitude) { return None; }
+		
+		return Some(self / magnitude);
+	}
+	
+	/// Creates a vector with the same direction as this vector but with the given magnitude
+	/// - **magnitude**: The magnitude of the new vector
+	/// 
+	/// **Returns**: Returns a vector with the given magnitude, or zero if this vector is zero
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// let vector = Vector2::new(3.0, 4.0);
+	/// let scaled = vector.with_magnitude(10.0);
+	/// assert_eq!(Vector2::new(6.0, 8.0), scaled);
+	/// assert_eq!(vector.normalize(), scaled.normalize());
+	/// assert_eq!(Vector2::zero(), Vector2::zero().with_magnitude(10.0));
+	/// ```
+	pub fn with_magnitude(self, magnitude: f32) -> Self { magnitude * self.normalize() }
 }
 
 /// Conversions
@@ -554,9 +1008,39 @@ impl From<Vector3> for Vector2 {
 	fn from(value: Vector3) -> Self { Vector2::from_vector3(value) }
 }
 
+/// Creates an empty 2D vector: (0, 0)
+/// #### Examples
+/// ```
+/// # use mathx::Vector2;
+/// assert_eq!(Vector2::zero(), Vector2::default());
+/// ```
+impl Default for Vector2 {
+	fn default() -> Self { Vector2::zero() }
+}
+
+/// Iterates through the components of the vector in x, y order
+/// #### Examples
+/// ```
+/// # use mathx::Vector2;
+/// let vector = Vector2::new(1.0, 2.0);
+/// assert_eq!(3.0, vector.into_iter().sum::<f32>());
+/// let mut iter = vector.into_iter();
+/// assert_eq!(Some(1.0), iter.next());
+/// assert_eq!(Some(2.0), iter.next());
+/// assert_eq!(None, iter.next());
+/// ```
+impl IntoIterator for Vector2 {
+	type Item = f32;
+	type IntoIter = core::array::IntoIter<f32, 2>;
+	
+	fn into_iter(self) -> Self::IntoIter { [self.x, self.y].into_iter() }
+}
+
 unsafe impl Send for Vector2 {}
 unsafe impl Sync for Vector2 {}
 
+const _: () = assert!(core::mem::size_of::<Vector2>() == 8 && core::mem::align_of::<Vector2>() == 4);
+
 // Equates
 impl Eq for Vector2 {}
 impl PartialEq for Vector2 {
@@ -566,6 +1050,50 @@ impl PartialEq for Vector2 {
 	}
 }
 
+/// Compares each component of the vector2s within an absolute epsilon, using the `approx` crate
+/// #### Examples
+/// ```
+/// # #[cfg(feature = "approx")] {
+/// # use mathx::Vector2;
+/// # use approx::{assert_abs_diff_eq, assert_abs_diff_ne};
+/// assert_abs_diff_eq!(Vector2::new(1.0, 2.0), Vector2::new(1.0000001, 2.0));
+/// assert_abs_diff_ne!(Vector2::new(1.0, 2.0), Vector2::new(1.1, 2.0));
+/// assert_abs_diff_eq!(Vector2::new(1.0, 2.0), Vector2::new(1.1, 2.0), epsilon = 0.2);
+/// # }
+/// ```
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Vector2 {
+	type Epsilon = f32;
+	
+	fn default_epsilon() -> Self::Epsilon { f32::default_epsilon() }
+	
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		f32::abs_diff_eq(&self.x, &other.x, epsilon)
+		&& f32::abs_diff_eq(&self.y, &other.y, epsilon)
+	}
+}
+
+/// Compares each component of the vector2s with a relative tolerance, using the `approx` crate
+/// #### Examples
+/// ```
+/// # #[cfg(feature = "approx")] {
+/// # use mathx::Vector2;
+/// # use approx::{assert_relative_eq, assert_relative_ne};
+/// assert_relative_eq!(Vector2::new(1.0, 2.0), Vector2::new(1.0000001, 2.0));
+/// assert_relative_ne!(Vector2::new(1.0, 2.0), Vector2::new(1.1, 2.0));
+/// assert_relative_eq!(Vector2::new(1.0, 2.0), Vector2::new(1.1, 2.0), max_relative = 0.2);
+/// # }
+/// ```
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Vector2 {
+	fn default_max_relative() -> Self::Epsilon { f32::default_max_relative() }
+	
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		f32::relative_eq(&self.x, &other.x, epsilon, max_relative)
+		&& f32::relative_eq(&self.y, &other.y, epsilon, max_relative)
+	}
+}
+
 // Display
 #[cfg(not(feature = "no_std"))]
 impl std::fmt::Display for Vector2 {
@@ -6,8 +6,22 @@ use crate::Vector2;
 use crate::{AddSubArithmetic, MulDivScalar, use_impl_ops, impl_add, impl_sub, impl_mul, impl_div};
 
 /// A 3D vector that holds an x-coordinate, y-coordinate, and z-coordinate
+/// #### Examples
+/// With the `bytemuck` feature, slices of vectors can be reinterpreted as raw floats and back
+/// ```
+/// # #[cfg(feature = "bytemuck")] {
+/// # use mathx::Vector3;
+/// let points = [Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0)];
+/// let floats: &[f32] = bytemuck::cast_slice(&points);
+/// assert_eq!(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], floats);
+/// let vectors: &[Vector3] = bytemuck::cast_slice(floats);
+/// assert_eq!(&points, vectors);
+/// # }
+/// ```
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 #[derive(Debug, Clone, Copy)]
+#[repr(C)]
 pub struct Vector3 {
 	/// The x coordinate of the vector
 	x: f32,
@@ -25,6 +39,8 @@ impl Vector3 {
 	/// - **z**: The z coordinate of the vector
 	/// 
 	/// **Returns**: Returns a new 3D vector
+	/// #### Remarks
+	/// This is a `const fn`, so it can be used to declare constants
 	/// #### Examples
 	/// ```
 	/// # use mathx::Vector3;
@@ -32,8 +48,13 @@ impl Vector3 {
 	/// assert_eq!(1.2, vector.x());
 	/// assert_eq!(3.45, vector.y());
 	/// assert_eq!(6.789, vector.z());
+	/// const GRAVITY: Vector3 = Vector3::new(0.0, -9.81, 0.0);
+	/// const FALL_SPEED: f32 = GRAVITY.y();
+	/// assert_eq!(-9.81, FALL_SPEED);
+	/// const DIRECTIONS: [Vector3; 2] = [Vector3::up(), Vector3::forward()];
+	/// assert_eq!(Vector3::forward(), DIRECTIONS[1]);
 	/// ```
-	pub fn new(x: f32, y: f32, z: f32) -> Self { Vector3 { x, y, z } }
+	pub const fn new(x: f32, y: f32, z: f32) -> Self { Vector3 { x, y, z } }
 	
 	/// Creates a new 3D vector from a 2D vector
 	/// - **vector**: The 2D vector to convert from
@@ -61,7 +82,7 @@ impl Vector3 {
 	/// assert_eq!(0.0, vector.y());
 	/// assert_eq!(0.0, vector.z());
 	/// ```
-	pub fn zero() -> Self { Vector3 { x: 0.0, y: 0.0, z: 0.0 } }
+	pub const fn zero() -> Self { Vector3 { x: 0.0, y: 0.0, z: 0.0 } }
 	
 	/// Creates a 3D unit vector that's pointing to the lefT: (-1, 0, 0)
 	/// 
@@ -74,7 +95,7 @@ impl Vector3 {
 	/// assert_eq!(0.0, vector.y());
 	/// assert_eq!(0.0, vector.z());
 	/// ```
-	pub fn left() -> Self { Vector3 { x: -1.0, y: 0.0, z: 0.0 } }
+	pub const fn left() -> Self { Vector3 { x: -1.0, y: 0.0, z: 0.0 } }
 	
 	/// Creates a 3D unit vector that's pointing to the right: (1, 0, 0)
 	/// 
@@ -87,7 +108,7 @@ impl Vector3 {
 	/// assert_eq!(0.0, vector.y());
 	/// assert_eq!(0.0, vector.z());
 	/// ```
-	pub fn right() -> Self { Vector3 { x: 1.0, y: 0.0, z: 0.0 } }
+	pub const fn right() -> Self { Vector3 { x: 1.0, y: 0.0, z: 0.0 } }
 	
 	/// Creates a 3D unit vector that's pointing up: (0, 1, 0)
 	/// 
@@ -100,7 +121,7 @@ impl Vector3 {
 	/// assert_eq!(1.0, vector.y());
 	/// assert_eq!(0.0, vector.z());
 	/// ```
-	pub fn up() -> Self { Vector3 { x: 0.0, y: 1.0, z: 0.0 } }
+	pub const fn up() -> Self { Vector3 { x: 0.0, y: 1.0, z: 0.0 } }
 	
 	/// Creates a 3D unit vector that's pointing down: (0, -1, 0)
 	/// 
@@ -113,7 +134,7 @@ impl Vector3 {
 	/// assert_eq!(-1.0, vector.y());
 	/// assert_eq!(0.0, vector.z());
 	/// ```
-	pub fn down() -> Self { Vector3 { x: 0.0, y: -1.0, z: 0.0 } }
+	pub const fn down() -> Self { Vector3 { x: 0.0, y: -1.0, z: 0.0 } }
 	
 	/// Creates a 3D unit vector that's pointing forward: (0, 0, 1)
 	/// 
@@ -126,7 +147,7 @@ impl Vector3 {
 	/// assert_eq!(0.0, vector.y());
 	/// assert_eq!(1.0, vector.z());
 	/// ```
-	pub fn forward() -> Self { Vector3 { x: 0.0, y: 0.0, z: 1.0 } }
+	pub const fn forward() -> Self { Vector3 { x: 0.0, y: 0.0, z: 1.0 } }
 	
 	/// Creates a 3D unit vector that's pointing backwards: (0, 0, -1)
 	/// 
@@ -139,7 +160,7 @@ impl Vector3 {
 	/// assert_eq!(0.0, vector.y());
 	/// assert_eq!(-1.0, vector.z());
 	/// ```
-	pub fn back() -> Self { Vector3 { x: 0.0, y: 0.0, z: -1.0 } }
+	pub const fn back() -> Self { Vector3 { x: 0.0, y: 0.0, z: -1.0 } }
 	
 	/// Creates a 3D vector that contains 1 in all it's components: (1, 1, 1)
 	/// 
@@ -152,7 +173,7 @@ impl Vector3 {
 	/// assert_eq!(1.0, vector.y());
 	/// assert_eq!(1.0, vector.z());
 	/// ```
-	pub fn one() -> Self { Vector3 { x: 1.0, y: 1.0, z: 1.0 } }
+	pub const fn one() -> Self { Vector3 { x: 1.0, y: 1.0, z: 1.0 } }
 	
 	/// Creates a 3D vector from two given angles
 	/// - **theta**: The first angle to create the vector from
@@ -206,6 +227,112 @@ impl Vector3 {
 	pub fn from_angles_deg(theta: f32, phi: f32) -> Self {
 		Vector3::from_angles(Math::deg2rad(theta), Math::deg2rad(phi))
 	}
+	
+	/// Creates a 3D vector from spherical coordinates
+	/// - **theta**: The first angle in radians to create the vector from
+	/// - **phi**: The second angle in radians to create the vector from
+	/// - **radius**: The length of the 3D vector
+	/// 
+	/// **Returns**: Returns a 3D vector pointing in the direction of the two angles with the given length
+	/// #### Remarks
+	/// The angles follow the same convention as [`Vector3::from_angles`]
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Math,Vector3,assert_range};
+	/// let vector = Vector3::from_spherical(0.0, 0.0, 5.0);
+	/// assert_range!(5.0, vector.x());
+	/// assert_range!(0.0, vector.y());
+	/// assert_range!(0.0, vector.z());
+	/// let vector = Vector3::from_spherical(Math::PI_OVER_2, 0.0, 5.0);
+	/// assert_range!(0.0, vector.x());
+	/// assert_range!(5.0, vector.y());
+	/// assert_range!(0.0, vector.z());
+	/// let vector = Vector3::from_spherical(0.0, Math::PI_OVER_2, 5.0);
+	/// assert_range!(0.0, vector.x());
+	/// assert_range!(0.0, vector.y());
+	/// assert_range!(5.0, vector.z());
+	/// ```
+	pub fn from_spherical(theta: f32, phi: f32, radius: f32) -> Self { radius * Vector3::from_angles(theta, phi) }
+	
+	/// Creates a 3D vector from spherical coordinates
+	/// - **theta**: The first angle in degrees to create the vector from
+	/// - **phi**: The second angle in degrees to create the vector from
+	/// - **radius**: The length of the 3D vector
+	/// 
+	/// **Returns**: Returns a 3D vector pointing in the direction of the two angles with the given length
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Math,Vector3,assert_range};
+	/// let vector = Vector3::from_spherical_deg(0.0, 0.0, 5.0);
+	/// assert_range!(5.0, vector.x());
+	/// assert_range!(0.0, vector.y());
+	/// assert_range!(0.0, vector.z());
+	/// let vector = Vector3::from_spherical_deg(90.0, 0.0, 5.0);
+	/// assert_range!(0.0, vector.x());
+	/// assert_range!(5.0, vector.y());
+	/// assert_range!(0.0, vector.z());
+	/// ```
+	pub fn from_spherical_deg(theta: f32, phi: f32, radius: f32) -> Self { radius * Vector3::from_angles_deg(theta, phi) }
+	
+	/// Creates a random 3D point inside of the unit sphere, uniformly distributed throughout its volume
+	/// - **rng**: The random number generator to sample with
+	/// 
+	/// **Returns**: Returns a random 3D point inside of the unit sphere
+	/// #### Examples
+	/// ```
+	/// # #[cfg(feature = "rand")] {
+	/// # use mathx::{Vector3,Math,assert_range};
+	/// # use rand::SeedableRng;
+	/// let mut rng = rand::rngs::StdRng::seed_from_u64(1234);
+	/// let mut sum = Vector3::zero();
+	/// for _ in 0..10000 {
+	/// 	let point = Vector3::random_in_unit_sphere(&mut rng);
+	/// 	assert!(point.magnitude() <= 1.0);
+	/// 	sum += point;
+	/// }
+	/// assert_range!(0.0, (sum / 10000.0).magnitude(), 0.05);
+	/// # }
+	/// ```
+	#[cfg(feature = "rand")]
+	pub fn random_in_unit_sphere<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+		loop {
+			let point = Vector3::new(
+				rng.gen_range(-1.0..=1.0),
+				rng.gen_range(-1.0..=1.0),
+				rng.gen_range(-1.0..=1.0)
+			);
+			
+			if point.square_magnitude() <= 1.0 { return point; }
+		}
+	}
+	
+	/// Creates a random 3D vector of unit length, uniformly distributed over the sphere
+	/// - **rng**: The random number generator to sample with
+	/// 
+	/// **Returns**: Returns a random 3D vector of unit length
+	/// #### Examples
+	/// ```
+	/// # #[cfg(feature = "rand")] {
+	/// # use mathx::{Vector3,Math,assert_range};
+	/// # use rand::SeedableRng;
+	/// let mut rng = rand::rngs::StdRng::seed_from_u64(1234);
+	/// let mut sum = Vector3::zero();
+	/// for _ in 0..10000 {
+	/// 	let vector = Vector3::random_unit(&mut rng);
+	/// 	assert_range!(1.0, vector.magnitude(), 0.0001);
+	/// 	sum += vector;
+	/// }
+	/// assert_range!(0.0, (sum / 10000.0).magnitude(), 0.05);
+	/// # }
+	/// ```
+	#[cfg(feature = "rand")]
+	pub fn random_unit<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+		let z: f32 = rng.gen_range(-1.0..=1.0);
+		let (sin, cos) = Math::sin_cos(rng.gen_range(0.0..Math::TWO_PI));
+		let radius = Math::sqrt(1.0 - z * z);
+		
+		return Vector3::new(radius * cos, radius * sin, z);
+	}
 }
 
 /// Properties
@@ -213,7 +340,7 @@ impl Vector3 {
 	/// Gets the x coordinate of the vector
 	/// 
 	/// **Returns**: Returns the x coordinate of the vector
-	pub fn x(&self) -> f32 { self.x }
+	pub const fn x(&self) -> f32 { self.x }
 	
 	/// Sets the x coordinate of the vector
 	/// - **value**: The value to set the x coordinate of the vector
@@ -222,7 +349,7 @@ impl Vector3 {
 	/// Gets the y coordinate of the vector
 	/// 
 	/// **Returns**: Returns the y coordinate of the vector
-	pub fn y(&self) -> f32 { self.y }
+	pub const fn y(&self) -> f32 { self.y }
 	
 	/// Sets the y coordinate of the vector
 	/// - **value**: The value to set the y coordinate of the vector
@@ -238,12 +365,42 @@ impl Vector3 {
 	/// Gets the z coordinate of the vector
 	/// 
 	/// **Returns**: Returns the z coordinate of the vector
-	pub fn z(&self) -> f32 { self.z }
+	pub const fn z(&self) -> f32 { self.z }
 	
 	/// Sets the z coordinate of the vector
 	/// - **value**: The value to set the z coordinate of the vector
 	pub fn set_z(&mut self, value: f32) { self.z = value; }
 	
+	/// Sets all the coordinates of the vector at once
+	/// - **x**: The value to set the x coordinate of the vector
+	/// - **y**: The value to set the y coordinate of the vector
+	/// - **z**: The value to set the z coordinate of the vector
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// let mut vector = Vector3::one();
+	/// vector.set(3.0, -4.0, 5.0);
+	/// assert_eq!(3.0, vector.x());
+	/// assert_eq!(-4.0, vector.y());
+	/// assert_eq!(5.0, vector.z());
+	/// ```
+	pub fn set(&mut self, x: f32, y: f32, z: f32) {
+		self.x = x;
+		self.y = y;
+		self.z = z;
+	}
+	
+	/// Sets all the coordinates of the vector at once from an array
+	/// - **values**: The array of values to set the x, y, and z coordinates (respectively) of the vector
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// let mut vector = Vector3::one();
+	/// vector.set_array([3.0, -4.0, 5.0]);
+	/// assert_eq!(Vector3::new(3.0, -4.0, 5.0), vector);
+	/// ```
+	pub fn set_array(&mut self, values: [f32; 3]) { self.set(values[0], values[1], values[2]); }
+	
 	/// Gets the magnitude of the vector. This returns the length of the vector
 	/// 
 	/// **Returns**: Returns the magnitude of the vector
@@ -263,6 +420,20 @@ impl Vector3 {
 		return Math::sqrt(magnitude);
 	}
 	
+	/// Sets the magnitude of the vector while keeping its direction
+	/// - **magnitude**: The magnitude to set the vector to
+	/// #### Remarks
+	/// A zero vector has no direction to keep, so it will stay as zero
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// let mut vector = Vector3::new(3.0, 4.0, 0.0);
+	/// vector.set_magnitude(10.0);
+	/// assert_eq!(Vector3::new(6.0, 8.0, 0.0), vector);
+	/// assert_eq!(10.0, vector.magnitude());
+	/// ```
+	pub fn set_magnitude(&mut self, magnitude: f32) { *self = self.with_magnitude(magnitude); }
+	
 	/// Gets the magnitude squared, avoiding the use of a square root
 	/// 
 	/// **Returns**: Returns the magnitude of the vector squared
@@ -277,6 +448,23 @@ impl Vector3 {
 
 /// Public Methods
 impl Vector3 {
+	/// Gets the absolute value of each component of the vector
+	/// 
+	/// **Returns**: Returns the vector with the absolute value applied to each component
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// let vector = Vector3::new(-1.5, 2.9, -0.1).abs();
+	/// assert_eq!(Vector3::new(1.5, 2.9, 0.1), vector);
+	/// ```
+	pub fn abs(self) -> Self {
+		Vector3::new(
+			Math::abs(self.x),
+			Math::abs(self.y),
+			Math::abs(self.z)
+		)
+	}
+	
 	/// Gets the angle between the two vectors in radians
 	/// - **rhs**: The other vector to get the angle from
 	/// 
@@ -305,9 +493,82 @@ impl Vector3 {
 	/// let a = Vector3::new(0.25, -0.5, 1.25);
 	/// let b = Vector3::new(2.0, 0.5, -1.0);
 	/// assert_range!(108.586, a.angle_between_deg(b), 0.01);
+	/// assert_range!(90.0, Vector3::right().angle_between_deg(Vector3::up()), 0.001);
 	/// ```
 	pub fn angle_between_deg(self, rhs: Vector3) -> f32 { return Math::rad2deg(self.angle_between(rhs)); }
 	
+	/// Bounces this vector off of a surface, reflecting it and scaling it down by the restitution
+	/// - **normal**: The normal vector of the surface to bounce off of
+	/// - **restitution**: How much of the vector's magnitude is kept after the bounce, clamped between 0.0 and 1.0
+	/// 
+	/// **Returns**: Returns the bounced vector
+	/// #### Remarks
+	/// A restitution of 1.0 is a perfect bounce that keeps the full magnitude, while 0.0 stops the vector entirely
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// let velocity = Vector3::new(3.0, -4.0, 1.0);
+	/// assert_eq!(Vector3::new(3.0, 4.0, 1.0), velocity.bounce(Vector3::up(), 1.0));
+	/// assert_eq!(Vector3::new(1.5, 2.0, 0.5), velocity.bounce(Vector3::up(), 0.5));
+	/// assert_eq!(Vector3::zero(), velocity.bounce(Vector3::up(), 0.0));
+	/// assert_eq!(Vector3::new(3.0, 4.0, 1.0), velocity.bounce(Vector3::up(), 2.0));
+	/// ```
+	pub fn bounce(self, normal: Vector3, restitution: f32) -> Self { Math::clamp(restitution, 0.0, 1.0) * self.reflect(normal) }
+	
+	/// Rounds up each component of the vector
+	/// 
+	/// **Returns**: Returns the vector with each component rounded up
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// let vector = Vector3::new(-1.5, 2.9, -0.1).ceil();
+	/// assert_eq!(Vector3::new(-1.0, 3.0, 0.0), vector);
+	/// ```
+	pub fn ceil(self) -> Self {
+		Vector3::new(
+			Math::ceil(self.x),
+			Math::ceil(self.y),
+			Math::ceil(self.z)
+		)
+	}
+	
+	/// Gets the Chebyshev distance (the largest difference of any component) between the two vectors
+	/// - **rhs**: The other vector to get the distance between
+	/// 
+	/// **Returns**: Returns the Chebyshev distance between the two vectors
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// let distance = Vector3::zero().chebyshev_distance(Vector3::new(3.0, 4.0, 0.0));
+	/// assert_eq!(4.0, distance);
+	/// let distance = Vector3::new(-1.0, 2.0, 3.0).chebyshev_distance(Vector3::new(3.0, 1.0, -3.0));
+	/// assert_eq!(6.0, distance);
+	/// ```
+	pub fn chebyshev_distance(self, rhs: Vector3) -> f32 {
+		Math::max(
+			Math::max(Math::abs(rhs.x - self.x), Math::abs(rhs.y - self.y)),
+			Math::abs(rhs.z - self.z)
+		)
+	}
+	
+	/// Compares the lengths of the two vectors, useful for sorting vectors by their length
+	/// - **other**: The other vector to compare with
+	/// 
+	/// **Returns**: Returns the ordering of this vector's length compared to the other vector's length,
+	/// or `None` if either vector contains `NaN`
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// let mut vectors = [Vector3::new(3.0, 4.0, 0.0), Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, -2.0, 0.0)];
+	/// vectors.sort_by(|a, b| a.cmp_magnitude(b).unwrap());
+	/// assert_eq!([Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, -2.0, 0.0), Vector3::new(3.0, 4.0, 0.0)], vectors);
+	/// let longest = vectors.iter().max_by(|a, b| a.cmp_magnitude(b).unwrap());
+	/// assert_eq!(Some(&Vector3::new(3.0, 4.0, 0.0)), longest);
+	/// ```
+	pub fn cmp_magnitude(&self, other: &Self) -> Option<core::cmp::Ordering> {
+		self.square_magnitude().partial_cmp(&other.square_magnitude())
+	}
+	
 	/// Performs a cross product and creates a 3D vector that is orthogonal to both vectors provided
 	/// - **rhs**: The other vector to cross product
 	/// 
@@ -343,6 +604,56 @@ impl Vector3 {
 	/// ```
 	pub fn distance(self, rhs: Vector3) -> f32 { (rhs - self).magnitude() }
 	
+	/// Gets the perpendicular distance between this point and an infinite line
+	/// - **line_point**: Any point that the line passes through
+	/// - **line_direction**: The direction of the line, does not need to be a unit vector
+	/// 
+	/// **Returns**: Returns the shortest distance between this point and the line
+	/// #### Remarks
+	/// If the line direction is zero, this returns the distance to the line point
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// let point = Vector3::new(5.0, 3.0, 0.0);
+	/// assert_eq!(3.0, point.distance_to_line(Vector3::zero(), Vector3::new(2.0, 0.0, 0.0)));
+	/// assert_eq!(3.0, point.distance_to_line(Vector3::new(-4.0, 0.0, 0.0), Vector3::left()));
+	/// assert_eq!(5.0, Vector3::new(3.0, 4.0, 0.0).distance_to_line(Vector3::zero(), Vector3::zero()));
+	/// ```
+	pub fn distance_to_line(self, line_point: Vector3, line_direction: Vector3) -> f32 {
+		let square_length = line_direction.square_magnitude();
+		let offset = self - line_point;
+		
+		if square_length == 0.0 { return offset.magnitude(); }
+		
+		return (offset - ((offset * line_direction) / square_length) * line_direction).magnitude();
+	}
+	
+	/// Gets the distance between this point and a finite line segment
+	/// - **start**: The starting point of the segment
+	/// - **end**: The ending point of the segment
+	/// 
+	/// **Returns**: Returns the distance between this point and the closest point on the segment, clamped between the start and end points
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// let start = Vector3::zero();
+	/// let end = Vector3::new(4.0, 0.0, 0.0);
+	/// assert_eq!(3.0, Vector3::new(2.0, 3.0, 0.0).distance_to_segment(start, end));
+	/// assert_eq!(5.0, Vector3::new(7.0, 4.0, 0.0).distance_to_segment(start, end));
+	/// assert_eq!(5.0, Vector3::new(-3.0, 0.0, 4.0).distance_to_segment(start, end));
+	/// assert_eq!(1.0, Vector3::new(1.0, 1.0, 2.0).distance_to_segment(Vector3::one(), Vector3::one()));
+	/// ```
+	pub fn distance_to_segment(self, start: Vector3, end: Vector3) -> f32 {
+		let diff = end - start;
+		let square_length = diff.square_magnitude();
+		
+		if square_length == 0.0 { return self.distance(start); }
+		
+		let t = Math::clamp(((self - start) * diff) / square_length, 0.0, 1.0);
+		
+		return self.distance(start + t * diff);
+	}
+	
 	/// Gets the dot product of between the two vectors.
 	/// It can be used to determine the angle between two vectors.
 	/// - **rhs**: The other vector to dot product with
@@ -382,6 +693,66 @@ impl Vector3 {
 		self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
 	}
 	
+	/// Rounds down each component of the vector
+	/// 
+	/// **Returns**: Returns the vector with each component rounded down
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// let vector = Vector3::new(-1.5, 2.9, -0.1).floor();
+	/// assert_eq!(Vector3::new(-2.0, 2.0, -1.0), vector);
+	/// ```
+	pub fn floor(self) -> Self {
+		Vector3::new(
+			Math::floor(self.x),
+			Math::floor(self.y),
+			Math::floor(self.z)
+		)
+	}
+	
+	/// Gets the exact bit patterns of each component, useful as a key for hashing and deduplicating
+	/// 
+	/// **Returns**: Returns the bit patterns of each component
+	/// #### Remarks
+	/// The key compares bitwise rather than approximately, so `-0.0` and `0.0` make different keys
+	/// and two 3D vectors that are approximately equal may still have different keys.
+	/// Vector3 does not implement `Hash`, since its approximate equality would break the rule that equal values hash the same,
+	/// so use this key when hashing is needed
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// use std::collections::HashMap;
+	/// let mut names = HashMap::new();
+	/// names.insert(Vector3::new(1.0, -2.5, 3.0).hash_key(), "first");
+	/// assert_eq!(Some(&"first"), names.get(&Vector3::new(1.0, -2.5, 3.0).hash_key()));
+	/// assert_ne!(Vector3::new(0.0, 0.0, 0.0).hash_key(), Vector3::new(-0.0, 0.0, 0.0).hash_key());
+	/// ```
+	pub fn hash_key(&self) -> [u32; 3] { [self.x.to_bits(), self.y.to_bits(), self.z.to_bits()] }
+	
+	/// Finds if the vector is approximately a unit vector (magnitude of 1)
+	/// 
+	/// **Returns**: Returns true if the magnitude of the vector is approximately 1
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// assert!(Vector3::new(1.0, 2.0, 2.0).normalize().is_normalized());
+	/// assert!(!Vector3::zero().is_normalized());
+	/// assert!(!Vector3::new(0.0, 2.0, 0.0).is_normalized());
+	/// ```
+	pub fn is_normalized(self) -> bool { Math::approx(self.square_magnitude(), 1.0) }
+	
+	/// Finds if the vector is approximately a zero vector
+	/// 
+	/// **Returns**: Returns true if all the components of the vector are approximately 0
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// assert!(Vector3::zero().is_zero());
+	/// assert!(!Vector3::new(1.0, 2.0, 2.0).normalize().is_zero());
+	/// assert!(!Vector3::new(0.0, 2.0, 0.0).is_zero());
+	/// ```
+	pub fn is_zero(self) -> bool { Math::approx(self.square_magnitude(), 0.0) }
+	
 	/// Linearly interpolates between the this and the other vector
 	/// - **rhs**: The other vector to end from
 	/// - **t**: The ratio value to interpolate between both vectors. Clamped between 0.0 and 1.0
@@ -418,6 +789,22 @@ impl Vector3 {
 		)
 	}
 	
+	/// Gets the Manhattan distance (the sum of the differences of each component) between the two vectors
+	/// - **rhs**: The other vector to get the distance between
+	/// 
+	/// **Returns**: Returns the Manhattan distance between the two vectors
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// let distance = Vector3::zero().manhattan_distance(Vector3::new(3.0, 4.0, 0.0));
+	/// assert_eq!(7.0, distance);
+	/// let distance = Vector3::new(-1.0, 2.0, 3.0).manhattan_distance(Vector3::new(3.0, 1.0, -3.0));
+	/// assert_eq!(11.0, distance);
+	/// ```
+	pub fn manhattan_distance(self, rhs: Vector3) -> f32 {
+		Math::abs(rhs.x - self.x) + Math::abs(rhs.y - self.y) + Math::abs(rhs.z - self.z)
+	}
+	
 	/// Moves this vector towards the target vector, it will never move past the target
 	/// - **target**: The target vector to move towards
 	/// - **delta**: The delta distance to try and move with, defines the maximum distance moved
@@ -444,9 +831,26 @@ impl Vector3 {
 		return diff * dir + self;
 	}
 	
+	/// Linearly interpolates between the this and the other vector, then normalizes the result
+	/// - **rhs**: The other vector to end from
+	/// - **t**: The ratio value to interpolate between both vectors. Clamped between 0.0 and 1.0
+	/// 
+	/// **Returns**: Returns the normalized interpolated vector
+	/// #### Remarks
+	/// This is a cheaper alternative to spherically interpolating the vectors as it avoids any trigonometry,
+	/// but the angle does not change at a constant speed as `t` moves from 0.0 to 1.0
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3,Math,assert_range};
+	/// let vector = Vector3::right().nlerp(Vector3::up(), 0.5);
+	/// assert_range!(1.0, vector.magnitude());
+	/// assert_eq!(Vector3::new(0.7071068, 0.7071068, 0.0), vector);
+	/// ```
+	pub fn nlerp(self, rhs: Vector3, t: f32) -> Self { self.lerp(rhs, t).normalize() }
+	
 	/// Normalizes the vector
 	/// 
-	/// **Returns**: Returns the unit vector version of this vector
+	/// **Returns**: Returns the unit vector version of this vector, or a zero vector if the magnitude is approximately zero
 	/// #### Examples
 	/// ```
 	/// # use mathx::{Vector3,Math,assert_range};
@@ -458,8 +862,55 @@ impl Vector3 {
 	/// assert_range!(-0.03843312, vector.x());
 	/// assert_range!(0.3843312, vector.y());
 	/// assert_range!(-0.9223949, vector.z());
+	/// assert_eq!(Vector3::zero(), Vector3::new(0.0, 1e-7, 0.0).normalize());
+	/// ```
+	pub fn normalize(self) -> Self { self.normalize_or(Vector3::zero()) }
+	
+	/// Normalizes the vector, using the fallback when the vector has no length to normalize
+	/// - **fallback**: The vector to return when this vector is zero
+	/// 
+	/// **Returns**: Returns the unit vector version of this vector, or the fallback if this vector is zero
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// assert_eq!(Vector3::new(0.0, 0.6, 0.8), Vector3::new(0.0, 3.0, 4.0).normalize_or(Vector3::forward()));
+	/// assert_eq!(Vector3::forward(), Vector3::zero().normalize_or(Vector3::forward()));
 	/// ```
-	pub fn normalize(self) -> Self { self / self.magnitude() }
+	pub fn normalize_or(self, fallback: Vector3) -> Self { self.try_normalize().unwrap_or(fallback) }
+	
+	/// Creates two vectors that are perpendicular to this vector and to each other, forming an orthonormal basis
+	/// 
+	/// **Returns**: Returns a tuple of two unit vectors that are perpendicular to this vector and to each other
+	/// #### Remarks
+	/// This vector is expected to be a unit vector. This uses the branchless construction from Duff et al.,
+	/// which stays stable for every direction, including the ones near an axis
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3,Math,assert_range};
+	/// let normal = Vector3::new(1.0, 2.0, 3.0).normalize();
+	/// let (tangent, bitangent) = normal.orthonormal_basis();
+	/// assert_range!(0.0, normal * tangent);
+	/// assert_range!(0.0, normal * bitangent);
+	/// assert_range!(0.0, tangent * bitangent);
+	/// assert_range!(1.0, tangent.magnitude());
+	/// assert_range!(1.0, bitangent.magnitude());
+	/// let (tangent, bitangent) = Vector3::back().orthonormal_basis();
+	/// assert_range!(0.0, Vector3::back() * tangent);
+	/// assert_range!(0.0, Vector3::back() * bitangent);
+	/// assert_range!(0.0, tangent * bitangent);
+	/// assert_range!(1.0, tangent.magnitude());
+	/// assert_range!(1.0, bitangent.magnitude());
+	/// ```
+	pub fn orthonormal_basis(self) -> (Vector3, Vector3) {
+		let sign = if self.z >= 0.0 { 1.0 } else { -1.0 };
+		let a = -1.0 / (sign + self.z);
+		let b = self.x * self.y * a;
+		
+		return (
+			Vector3::new(1.0 + sign * self.x * self.x * a, sign * b, -sign * self.x),
+			Vector3::new(b, sign + self.y * self.y * a, -self.y)
+		);
+	}
 	
 	/// Projects this vector onto the given vector
 	/// - **rhs**: The vector to project onto
@@ -480,6 +931,21 @@ impl Vector3 {
 		return (top / bottom) * rhs;
 	}
 	
+	/// Projects this vector onto the plane defined by the given normal
+	/// - **plane_normal**: The normal of the plane to project onto, does not need to be a unit vector
+	/// 
+	/// **Returns**: Returns the vector projected onto the plane
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// let vector = Vector3::one();
+	/// let normal = Vector3::new(0.0, 1.0, 0.0);
+	/// let expected = Vector3::new(1.0, 0.0, 1.0);
+	/// assert_eq!(expected, vector.project_on_plane(normal));
+	/// assert_eq!(expected, vector.project_on_plane(5.0 * normal));
+	/// ```
+	pub fn project_on_plane(self, plane_normal: Vector3) -> Self { self.reject(plane_normal) }
+	
 	/// Rejects this vector from the given vector
 	/// - **rhs**: The vector to reject from
 	/// 
@@ -518,6 +984,73 @@ impl Vector3 {
 		return dot * normal + self;
 	}
 	
+	/// Refracts this vector through a surface using Snell's law
+	/// - **normal**: The normal vector of the surface, facing against this vector
+	/// - **eta**: The ratio of the refractive indices, from the medium being left over the medium being entered
+	/// 
+	/// **Returns**: Returns the refracted vector, or `None` if the vector is totally reflected internally
+	/// #### Remarks
+	/// Both this vector and the normal are expected to be normalized. This vector points towards the surface,
+	/// while the normal points away from the surface, back towards where this vector came from
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3,Math,assert_range};
+	/// let refracted = Vector3::down().refract(Vector3::up(), 1.0 / 1.5);
+	/// assert_eq!(Some(Vector3::down()), refracted);
+	/// let direction = Vector3::new(1.0, -1.0, 0.0).normalize();
+	/// let refracted = direction.refract(Vector3::up(), 1.0 / 1.5).unwrap();
+	/// assert_range!(0.47140452, refracted.x());
+	/// assert_range!(1.0, refracted.magnitude());
+	/// let direction = Vector3::new(0.8660254, -0.5, 0.0);
+	/// assert_eq!(None, direction.refract(Vector3::up(), 1.5));
+	/// ```
+	pub fn refract(self, normal: Vector3, eta: f32) -> Option<Self> {
+		let dot = self * normal;
+		let k = 1.0 - eta * eta * (1.0 - dot * dot);
+		
+		if k < 0.0 { return None; }
+		
+		return Some(eta * self - (eta * dot + Math::sqrt(k)) * normal);
+	}
+	
+	/// Rotates the vector around the given axis using Rodrigues' rotation formula
+	/// - **axis**: The axis to rotate around, does not need to be a unit vector
+	/// - **angle**: The angle to rotate the vector by in radians
+	/// 
+	/// **Returns**: Returns the rotated vector
+	/// #### Remarks
+	/// This is a lighter alternative to creating a quaternion for one-off rotations
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3,Math};
+	/// let vector = Vector3::right().rotate_around_axis(Vector3::up(), Math::PI_OVER_2);
+	/// assert_eq!(Vector3::back(), vector);
+	/// let vector = Vector3::new(1.0, 2.0, 3.0).rotate_around_axis(Vector3::new(0.0, 0.0, 5.0), Math::PI);
+	/// assert_eq!(Vector3::new(-1.0, -2.0, 3.0), vector);
+	/// ```
+	pub fn rotate_around_axis(self, axis: Vector3, angle: f32) -> Self {
+		let axis = axis.normalize();
+		let (sin, cos) = Math::sin_cos(angle);
+		
+		return cos * self + sin * axis.cross(self) + ((1.0 - cos) * (axis * self)) * axis;
+	}
+	
+	/// Rotates the vector around the given axis by the given angle in degrees
+	/// - **axis**: The axis to rotate around, does not need to be a unit vector
+	/// - **angle**: The angle to rotate the vector by in degrees
+	/// 
+	/// **Returns**: Returns the rotated vector
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3,Math};
+	/// let vector = Vector3::right().rotate_around_axis_deg(Vector3::up(), 90.0);
+	/// assert_eq!(Vector3::back(), vector);
+	/// assert_eq!(Vector3::right().rotate_around_axis(Vector3::up(), Math::PI_OVER_2), vector);
+	/// let vector = Vector3::new(1.0, 2.0, 3.0).rotate_around_axis_deg(Vector3::new(0.0, 0.0, 5.0), 180.0);
+	/// assert_eq!(Vector3::new(-1.0, -2.0, 3.0), vector);
+	/// ```
+	pub fn rotate_around_axis_deg(self, axis: Vector3, angle: f32) -> Self { self.rotate_around_axis(axis, Math::deg2rad(angle)) }
+	
 	/// Rotates the vector around towards the target vector
 	/// - **target**: The target vector to rotate towards
 	/// - **radians_delta**: The maximum angle delta the vector will rotate in radians
@@ -563,6 +1096,23 @@ impl Vector3 {
 		return rotated.normalize() * towards_magnitude;
 	}
 	
+	/// Rounds each component of the vector to the nearest whole number
+	/// 
+	/// **Returns**: Returns the vector with each component rounded to the nearest whole number
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// let vector = Vector3::new(-1.5, 2.9, -0.1).round();
+	/// assert_eq!(Vector3::new(-2.0, 3.0, 0.0), vector);
+	/// ```
+	pub fn round(self) -> Self {
+		Vector3::new(
+			Math::round(self.x),
+			Math::round(self.y),
+			Math::round(self.z)
+		)
+	}
+	
 	/// Scales the vector using another vector, multiplying everything component-wise
 	/// - **rhs**: The other vector to scale with
 	/// 
@@ -616,6 +1166,8 @@ impl Vector3 {
 	/// let b = Vector3::new(2.0, 0.5, -1.0);
 	/// let axis = Vector3::new(1.0, -1.0, 0.0);
 	/// assert_range!(-108.586, a.signed_angle_between_deg(b, axis), 0.01);
+	/// assert_range!(90.0, Vector3::right().signed_angle_between_deg(Vector3::up(), Vector3::forward()), 0.001);
+	/// assert_range!(-90.0, Vector3::right().signed_angle_between_deg(Vector3::up(), Vector3::back()), 0.001);
 	/// ```
 	pub fn signed_angle_between_deg(self, rhs: Vector3, axis: Vector3) -> f32 { Math::rad2deg(self.signed_angle_between(rhs, axis)) }
 	
@@ -624,16 +1176,27 @@ impl Vector3 {
 	/// - **t**: The ratio (t) to interpolate with
 	/// 
 	/// **Returns**: Returns the spherically interpolated vector
+	/// #### Remarks
+	/// The direction rotates along the arc between both vectors at a constant angular speed,
+	/// while the magnitude is linearly interpolated separately
 	/// #### Examples
 	/// ```
 	/// # use mathx::{Vector3,Math,assert_range};
 	/// let a = Vector3::new(1.0, 3.0, 4.0);
 	/// let b = Vector3::new(4.0, 6.0, 7.0);
-	/// let actual = Vector3::slerp_unclamped(a, b, 0.7);
+	/// let actual = Vector3::slerp(a, b, 0.7);
 	/// let expected = Vector3::new(2.903773, 5.117129, 6.223807);
 	/// assert_range!(expected.x(), actual.x(), 0.0001);
 	/// assert_range!(expected.y(), actual.y(), 0.0001);
 	/// assert_range!(expected.z(), actual.z(), 0.0001);
+	/// let actual = Vector3::right().slerp(Vector3::up(), 0.5);
+	/// assert_range!(0.70710678, actual.x());
+	/// assert_range!(0.70710678, actual.y());
+	/// assert_range!(0.0, actual.z());
+	/// assert_range!(45.0, actual.angle_between_deg(Vector3::right()), 0.01);
+	/// let actual = Vector3::right().slerp(Vector3::left(), 0.5);
+	/// assert_range!(1.0, actual.magnitude());
+	/// assert_range!(0.0, actual.dot(Vector3::right()));
 	/// ```
 	pub fn slerp(self, rhs: Vector3, t: f32) -> Self { self.slerp_unclamped(rhs, Math::clamp(t, 0.0, 1.0)) }
 	
@@ -656,20 +1219,23 @@ impl Vector3 {
 	pub fn slerp_unclamped(self, rhs: Vector3, t: f32) -> Self {
 		let size = Math::lerp_unclamped(self.magnitude(), rhs.magnitude(), t);
 		let unit_self = self.normalize();
-		let mut unit_rhs = rhs.normalize();
-		let mut dot = unit_self.dot(unit_rhs);
+		let unit_rhs = rhs.normalize();
+		let dot = Math::clamp(unit_self.dot(unit_rhs), -1.0, 1.0);
 		
-		if dot < 0.0 {
-			unit_rhs = -unit_rhs;
-			dot = -dot;
-		}
 		if dot > 0.9995 {
 			return size * (unit_self + t * (unit_rhs - unit_self)).normalize();
 		}
 		
 		let angle = t * Math::acos(dot);
-		let unit = dot * unit_self;
-		let unit_rhs = (unit_rhs - unit).normalize();
+		let mut unit_rhs = unit_rhs - dot * unit_self;
+		
+		if unit_rhs.square_magnitude() < 0.000001 {
+			// The vectors are opposite each other, so any perpendicular arc will work
+			let axis = if Math::abs(unit_self.x) < 0.9 { Vector3::right() } else { Vector3::up() };
+			unit_rhs = unit_self.cross(axis);
+		}
+		
+		let unit_rhs = unit_rhs.normalize();
 		let (sin, cos) = Math::sin_cos(angle);
 		
 		return size * cos * unit_self + size * sin * unit_rhs;
@@ -738,10 +1304,87 @@ impl Vector3 {
 		
 		return (result, velocity);
 	}
+	
+	/// Normalizes the vector, if it has any length to normalize
+	/// 
+	/// **Returns**: Returns the unit vector version of this vector, or `None` if this vector is approximately zero
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// assert_eq!(Some(Vector3::new(0.0, 0.6, 0.8)), Vector3::new(0.0, 3.0, 4.0).try_normalize());
+	/// assert_eq!(None, Vector3::zero().try_normalize());
+	/// assert_eq!(None, Vector3::new(0.0000001, 0.0, 0.0).try_normalize());
+	/// ```
+	pub fn try_normalize(self) -> Option<Self> {
+		let magnitude = self.magnitude();
+		
+		if Math::approx_zero(magnitude) { return None; }
+		
+		return Some(self / magnitude);
+	}
+	
+	/// Creates a vector with the same direction as this vector but with the given magnitude
+	/// - **magnitude**: The magnitude of the new vector
+	/// 
+	/// **Returns**: Returns a vector with the given magnitude, or zero if this vector is zero
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// let vector = Vector3::new(3.0, 4.0, 0.0);
+	/// let scaled = vector.with_magnitude(10.0);
+	/// assert_eq!(Vector3::new(6.0, 8.0, 0.0), scaled);
+	/// assert_eq!(vector.normalize(), scaled.normalize());
+	/// assert_eq!(Vector3::zero(), Vector3::zero().with_magnitude(10.0));
+	/// ```
+	pub fn with_magnitude(self, magnitude: f32) -> Self { magnitude * self.normalize() }
 }
 
 /// Conversions
 impl Vector3 {
+	/// Gets the two angles that point in the direction of this vector, the inverse of [`Vector3::from_angles`]
+	/// 
+	/// **Returns**: Returns a tuple of the theta and phi angles (respectively) in radians. Returns (0.0, 0.0) if this vector is zero
+	/// #### Remarks
+	/// The vector does not need to be a unit vector, as only its direction is used
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Math,Vector3,assert_range};
+	/// let (theta, phi) = Vector3::from_angles(Math::PI_OVER_4, Math::PI_OVER_4).to_angles();
+	/// assert_range!(Math::PI_OVER_4, theta);
+	/// assert_range!(Math::PI_OVER_4, phi);
+	/// let (theta, phi) = Vector3::from_angles(-2.0, -1.2).to_angles();
+	/// assert_range!(-2.0, theta);
+	/// assert_range!(-1.2, phi);
+	/// let direction = Vector3::new(-3.0, 4.0, 5.0);
+	/// let (theta, phi) = direction.to_angles();
+	/// assert_eq!(direction.normalize(), Vector3::from_angles(theta, phi));
+	/// assert_eq!((0.0, 0.0), Vector3::zero().to_angles());
+	/// ```
+	pub fn to_angles(self) -> (f32, f32) {
+		if self.square_magnitude() == 0.0 { return (0.0, 0.0); }
+		
+		return (Math::atan2(self.y, self.x), Math::atan2(self.z, Math::hypot(self.x, self.y)));
+	}
+	
+	/// Gets the two angles that point in the direction of this vector in degrees, the inverse of [`Vector3::from_angles_deg`]
+	/// 
+	/// **Returns**: Returns a tuple of the theta and phi angles (respectively) in degrees. Returns (0.0, 0.0) if this vector is zero
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Math,Vector3,assert_range};
+	/// let (theta, phi) = Vector3::from_angles_deg(45.0, 45.0).to_angles_deg();
+	/// assert_range!(45.0, theta, 0.001);
+	/// assert_range!(45.0, phi, 0.001);
+	/// let (theta, phi) = Vector3::from_angles_deg(-127.0, 30.0).to_angles_deg();
+	/// assert_range!(-127.0, theta, 0.001);
+	/// assert_range!(30.0, phi, 0.001);
+	/// ```
+	pub fn to_angles_deg(self) -> (f32, f32) {
+		let (theta, phi) = self.to_angles();
+		
+		return (Math::rad2deg(theta), Math::rad2deg(phi));
+	}
+	
 	pub fn to_vector2(self) -> Vector2 { Vector2::new(self.x, self.y) }
 }
 
@@ -749,9 +1392,40 @@ impl From<Vector2> for Vector3 {
 	fn from(value: Vector2) -> Self { Vector3::from_vector2(value) }
 }
 
+/// Creates an empty 3D vector: (0, 0, 0)
+/// #### Examples
+/// ```
+/// # use mathx::Vector3;
+/// assert_eq!(Vector3::zero(), Vector3::default());
+/// ```
+impl Default for Vector3 {
+	fn default() -> Self { Vector3::zero() }
+}
+
+/// Iterates through the components of the vector in x, y, z order
+/// #### Examples
+/// ```
+/// # use mathx::Vector3;
+/// let vector = Vector3::new(1.0, 2.0, 3.0);
+/// assert_eq!(6.0, vector.into_iter().sum::<f32>());
+/// let mut iter = vector.into_iter();
+/// assert_eq!(Some(1.0), iter.next());
+/// assert_eq!(Some(2.0), iter.next());
+/// assert_eq!(Some(3.0), iter.next());
+/// assert_eq!(None, iter.next());
+/// ```
+impl IntoIterator for Vector3 {
+	type Item = f32;
+	type IntoIter = core::array::IntoIter<f32, 3>;
+	
+	fn into_iter(self) -> Self::IntoIter { [self.x, self.y, self.z].into_iter() }
+}
+
 unsafe impl Send for Vector3 {}
 unsafe impl Sync for Vector3 {}
 
+const _: () = assert!(core::mem::size_of::<Vector3>() == 12 && core::mem::align_of::<Vector3>() == 4);
+
 // Equates
 impl Eq for Vector3 {}
 impl PartialEq for Vector3 {
@@ -762,6 +1436,52 @@ impl PartialEq for Vector3 {
 	}
 }
 
+/// Compares each component of the vector3s within an absolute epsilon, using the `approx` crate
+/// #### Examples
+/// ```
+/// # #[cfg(feature = "approx")] {
+/// # use mathx::Vector3;
+/// # use approx::{assert_abs_diff_eq, assert_abs_diff_ne};
+/// assert_abs_diff_eq!(Vector3::new(1.0, 2.0, 3.0), Vector3::new(1.0, 2.0000001, 3.0));
+/// assert_abs_diff_ne!(Vector3::new(1.0, 2.0, 3.0), Vector3::new(1.0, 2.1, 3.0));
+/// assert_abs_diff_eq!(Vector3::new(1.0, 2.0, 3.0), Vector3::new(1.0, 2.1, 3.0), epsilon = 0.2);
+/// # }
+/// ```
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Vector3 {
+	type Epsilon = f32;
+	
+	fn default_epsilon() -> Self::Epsilon { f32::default_epsilon() }
+	
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		f32::abs_diff_eq(&self.x, &other.x, epsilon)
+		&& f32::abs_diff_eq(&self.y, &other.y, epsilon)
+		&& f32::abs_diff_eq(&self.z, &other.z, epsilon)
+	}
+}
+
+/// Compares each component of the vector3s with a relative tolerance, using the `approx` crate
+/// #### Examples
+/// ```
+/// # #[cfg(feature = "approx")] {
+/// # use mathx::Vector3;
+/// # use approx::{assert_relative_eq, assert_relative_ne};
+/// assert_relative_eq!(Vector3::new(1.0, 2.0, 3.0), Vector3::new(1.0, 2.0000001, 3.0));
+/// assert_relative_ne!(Vector3::new(1.0, 2.0, 3.0), Vector3::new(1.0, 2.1, 3.0));
+/// assert_relative_eq!(Vector3::new(1.0, 2.0, 3.0), Vector3::new(1.0, 2.1, 3.0), max_relative = 0.2);
+/// # }
+/// ```
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Vector3 {
+	fn default_max_relative() -> Self::Epsilon { f32::default_max_relative() }
+	
+	fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+		f32::relative_eq(&self.x, &other.x, epsilon, max_relative)
+		&& f32::relative_eq(&self.y, &other.y, epsilon, max_relative)
+		&& f32::relative_eq(&self.z, &other.z, epsilon, max_relative)
+	}
+}
+
 // Display
 #[cfg(not(feature = "no_std"))]
 impl std::fmt::Display for Vector3 {